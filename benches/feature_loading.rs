@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use fargin::features::{FeatureManager, NewFeature};
+use tempfile::tempdir;
+
+const FEATURES: usize = 500;
+
+fn load_features(c: &mut Criterion) {
+    let project = tempdir().unwrap();
+    let mut manager = FeatureManager::new(project.path()).unwrap();
+    for i in 0..FEATURES {
+        manager
+            .add_feature(NewFeature {
+                name: format!("Feature {}", i),
+                description: Some(format!("Generated feature number {}", i)),
+                tags: vec![format!("batch-{}", i % 7)],
+                acceptance_criteria: vec!["It works".to_string()],
+                ..Default::default()
+            })
+            .unwrap();
+    }
+
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut group = c.benchmark_group("load_features");
+    group.bench_function("serial", |b| {
+        b.iter(|| FeatureManager::open_with_threads(project.path(), 1).unwrap())
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| FeatureManager::open_with_threads(project.path(), threads).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, load_features);
+criterion_main!(benches);
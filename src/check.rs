@@ -1,28 +1,228 @@
-use crate::features::FeatureStatus;
-use anyhow::Result;
+use crate::config::{fargin_dir, FARGIN_SUBDIRS};
+use crate::features::{FeatureManager, FeatureStatus};
+use crate::{eoutln, outln};
+use anyhow::{Context, Result};
+use chrono::Utc;
 use log::{debug, error, info, warn};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::SystemTime;
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long a git status result can be reused between rapid invocations
+const GIT_STATUS_CACHE_TTL: Duration = Duration::from_secs(2);
+
+/// Git status result cached alongside the repository state it was computed from
+struct CachedGitStatus {
+    fingerprint: GitFingerprint,
+    cached_at: Instant,
+    report: GitHealthReport,
+}
+
+/// Modification times of the files that change whenever the git status changes
+#[derive(Clone, PartialEq, Eq)]
+struct GitFingerprint {
+    head_modified: Option<SystemTime>,
+    index_modified: Option<SystemTime>,
+}
+
+impl GitFingerprint {
+    fn read(git_dir: &Path) -> Self {
+        let modified = |name: &str| {
+            fs::metadata(git_dir.join(name))
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+
+        Self {
+            head_modified: modified("HEAD"),
+            index_modified: modified("index"),
+        }
+    }
+}
+
+/// Process-wide git status cache keyed by project root
+fn git_status_cache() -> &'static Mutex<HashMap<PathBuf, CachedGitStatus>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, CachedGitStatus>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Days without updates after which a feature is reported as stale
+pub const DEFAULT_STALE_AFTER_DAYS: u64 = 30;
 
 /// Comprehensive project health and consistency checker
 pub struct ProjectChecker {
     project_root: PathBuf,
+    stale_after_days: u64,
+    timeout: Option<Duration>,
+    steps: Option<Vec<CheckStep>>,
+    cargo_bin: String,
+    include_outdated: bool,
+}
+
+/// Toolchain a project is built with, detected from its manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectKind {
+    Rust,
+    Python,
+    JavaScript,
+    Unknown,
+}
+
+impl ProjectKind {
+    /// Detect the toolchain from `Cargo.toml`, `pyproject.toml` or `package.json`
+    pub fn detect(project_root: &Path) -> Self {
+        if project_root.join("Cargo.toml").exists() {
+            ProjectKind::Rust
+        } else if project_root.join("pyproject.toml").exists() {
+            ProjectKind::Python
+        } else if project_root.join("package.json").exists() {
+            ProjectKind::JavaScript
+        } else {
+            ProjectKind::Unknown
+        }
+    }
+
+    /// Format, lint and test steps run by `run_project_checks`
+    ///
+    /// Rust steps run `cargo_bin` in place of `cargo`.
+    pub fn default_steps(self, cargo_bin: &str) -> Vec<CheckStep> {
+        match self {
+            ProjectKind::Rust => vec![
+                CheckStep::new("Cargo Formatting Check", cargo_bin, &["fmt"]),
+                CheckStep::new(
+                    "Cargo Clippy Linting",
+                    cargo_bin,
+                    &["clippy", "--", "-D", "warnings"],
+                ),
+                CheckStep::new("Cargo Test Suite", cargo_bin, &["test"]),
+            ],
+            ProjectKind::Python => vec![
+                CheckStep::new("Ruff Linting", "ruff", &["check", "."]),
+                CheckStep::new("Black Formatting Check", "black", &["--check", "."]),
+                CheckStep::new("Pytest Suite", "pytest", &[]),
+            ],
+            ProjectKind::JavaScript => vec![
+                CheckStep::new("ESLint Linting", "npx", &["--no-install", "eslint", "."]),
+                CheckStep::new("Jest Test Suite", "npx", &["--no-install", "jest"]),
+            ],
+            ProjectKind::Unknown => Vec::new(),
+        }
+    }
+}
+
+/// A single named command run as part of the project checks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckStep {
+    pub name: String,
+    #[serde(alias = "command")]
+    pub program: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl CheckStep {
+    pub fn new(name: &str, program: &str, args: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+}
+
+/// Error for a run in which every step was skipped because its program is missing
+fn no_checks_ran(steps: &[CheckStep]) -> anyhow::Error {
+    let mut programs: Vec<&str> = steps.iter().map(|step| step.program.as_str()).collect();
+    programs.dedup();
+    eoutln!("❌ No checks ran: {} not installed", programs.join(", "));
+    CheckFailed(format!(
+        "No checks ran: {} not installed",
+        programs.join(", ")
+    ))
+    .into()
+}
+
+/// Whether an error comes from a program that is not installed
+fn is_not_found(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound)
 }
 
 impl ProjectChecker {
     /// Create a new project checker
+    ///
+    /// Cargo commands use the `cargo_bin` from the project config, or plain
+    /// `cargo` when the project has no readable config.
     pub fn new(project_root: &Path) -> Self {
+        let cargo_bin = match crate::config::ProjectConfig::load(project_root) {
+            Ok(config) => config.cargo_bin,
+            Err(e) => {
+                debug!("Using default cargo binary: {:#}", e);
+                "cargo".to_string()
+            }
+        };
+
         Self {
             project_root: project_root.to_path_buf(),
+            stale_after_days: DEFAULT_STALE_AFTER_DAYS,
+            timeout: None,
+            steps: None,
+            cargo_bin,
+            include_outdated: false,
+        }
+    }
+
+    /// Run cargo commands with this binary instead of the configured one
+    pub fn with_cargo_bin(mut self, cargo_bin: impl Into<String>) -> Self {
+        self.cargo_bin = cargo_bin.into();
+        self
+    }
+
+    /// Steps `run_project_checks` runs before any custom steps
+    pub fn steps(&self) -> Vec<CheckStep> {
+        match &self.steps {
+            Some(steps) => steps.clone(),
+            None => {
+                let kind = ProjectKind::detect(&self.project_root);
+                debug!("Detected {:?} project", kind);
+                kind.default_steps(&self.cargo_bin)
+            }
         }
     }
 
+    /// Use a custom staleness window for `run_all_checks`
+    pub fn with_stale_after_days(mut self, days: u64) -> Self {
+        self.stale_after_days = days;
+        self
+    }
+
+    /// List outdated dependencies in `check_dependencies` using `check_outdated`
+    pub fn with_outdated_dependencies(mut self, include: bool) -> Self {
+        self.include_outdated = include;
+        self
+    }
+
+    /// Run these steps in `run_project_checks` instead of the detected toolchain's
+    pub fn with_steps(mut self, steps: Vec<CheckStep>) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Kill any check subprocess that runs longer than `timeout`
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// Run all project checks
     pub fn run_all_checks(&self) -> Result<ProjectHealthReport> {
         Ok(ProjectHealthReport {
-            feature_health: self.check_feature_health()?,
+            feature_health: self.check_feature_health(self.stale_after_days)?,
             file_structure: self.check_file_structure()?,
             dependency_health: self.check_dependencies()?,
             git_health: self.check_git_status()?,
@@ -30,8 +230,12 @@ impl ProjectChecker {
     }
 
     /// Run comprehensive project checks similar to ./check.sh
+    ///
+    /// The steps come from the detected `ProjectKind` unless overridden with
+    /// `with_steps`. Steps whose program is not installed are skipped, but the
+    /// run fails if that leaves nothing to run.
     pub fn run_project_checks(&self) -> Result<()> {
-        println!("🔍 Starting comprehensive project checks");
+        outln!("🔍 Starting comprehensive project checks");
         info!("Starting comprehensive project checks");
         debug!("Project path: {}", self.project_root.display());
 
@@ -39,153 +243,308 @@ impl ProjectChecker {
         fn run_command_with_streaming(
             cmd: &mut std::process::Command,
             stage: String,
+            timeout: Option<Duration>,
         ) -> Result<()> {
-            use log::{debug, error, info, warn};
-            use std::io::{BufRead, BufReader};
-            use std::process::Stdio;
-            use std::sync::mpsc;
-
             info!("Running {}...", stage);
             debug!("Executing command: {:?}", cmd);
-            println!("\n🚀 {}", stage);
+            outln!("\n🚀 {}", stage);
 
-            let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+            let (status, _, _) = stream_command(
+                cmd,
+                &stage,
+                timeout,
+                crate::output::human_stdout(),
+                std::io::stderr(),
+            )?;
 
-            // Create channels for stdout and stderr
-            let (stdout_tx, stdout_rx) = mpsc::channel();
-            let (stderr_tx, stderr_rx) = mpsc::channel();
+            if !status.success() {
+                error!("{} failed", stage);
+                eoutln!("❌ {} failed", stage);
+                return Err(CheckFailed(format!("{} failed", stage)).into());
+            }
 
-            // Stream stdout
-            let stdout = child.stdout.take().expect("Failed to capture stdout");
-            let stdout_stage = stage.clone();
-            std::thread::spawn(move || {
-                let stdout_reader = BufReader::new(stdout);
-                for line in stdout_reader.lines().map_while(Result::ok) {
-                    let _ = stdout_tx.send(line);
-                }
-            });
+            info!("{} passed", stage);
+            outln!("✅ {} passed", stage);
+            Ok(())
+        }
 
-            // Stream stderr
-            let stderr = child.stderr.take().expect("Failed to capture stderr");
-            std::thread::spawn(move || {
-                let stderr_reader = BufReader::new(stderr);
-                for line in stderr_reader.lines().map_while(Result::ok) {
-                    let _ = stderr_tx.send(line);
-                }
-            });
+        // Each command runs in the project root; the process working directory
+        // is shared by every thread, so it is never changed here
+        let steps = self.steps();
 
-            // Receive and print stdout
-            std::thread::spawn(move || {
-                while let Ok(line) = stdout_rx.recv() {
-                    println!("{}", line);
-                    debug!("{} stdout: {}", stdout_stage, line);
+        let custom_steps = self.custom_steps()?;
+
+        if steps.is_empty() && custom_steps.is_empty() {
+            warn!(
+                "No project type detected in {}",
+                self.project_root.display()
+            );
+            outln!(
+                "⚠️  No Cargo.toml, pyproject.toml or package.json found; there are no checks to run"
+            );
+            return Err(CheckFailed("No checks to run".to_string()).into());
+        }
+
+        let mut skipped = 0;
+        for step in &steps {
+            let mut cmd = Command::new(&step.program);
+            cmd.args(&step.args).current_dir(&self.project_root);
+
+            match run_command_with_streaming(&mut cmd, step.name.clone(), self.timeout) {
+                Err(e) if is_not_found(&e) => {
+                    warn!("{} skipped: {} is not installed", step.name, step.program);
+                    outln!(
+                        "⏭️  {} skipped: {} is not installed",
+                        step.name,
+                        step.program
+                    );
+                    skipped += 1;
                 }
-            });
+                result => result?,
+            }
+        }
 
-            // Receive and print stderr
-            let stage_clone = stage.clone();
-            std::thread::spawn(move || {
-                while let Ok(line) = stderr_rx.recv() {
-                    eprintln!("{}", line);
-                    warn!("{} stderr: {}", stage_clone, line);
+        if skipped == steps.len() && custom_steps.is_empty() {
+            return Err(no_checks_ran(&steps));
+        }
+
+        // Custom steps were asked for explicitly, so a missing program fails the run
+        for step in &custom_steps {
+            outln!("\n🚀 {}", step.name);
+            if let Err(e) = self.run_prefixed_stage(step) {
+                eoutln!("❌ {} failed", step.name);
+                return Err(e);
+            }
+        }
+
+        info!("All project checks completed successfully");
+        outln!("🎉 All project checks completed successfully!");
+        Ok(())
+    }
+
+    /// Run the same steps as `run_project_checks` concurrently
+    ///
+    /// `cargo fmt` is only checked, not applied, so it can't race with the
+    /// other stages. Custom steps from `checks.toml` run alongside them. Every
+    /// stage runs to completion and the returned error lists all stages that
+    /// failed.
+    pub fn run_project_checks_parallel(&self) -> Result<()> {
+        outln!("🔍 Starting project checks in parallel");
+        info!("Starting parallel project checks");
+
+        let mut steps = self.steps();
+        for step in &mut steps {
+            if step.program == self.cargo_bin && step.args == ["fmt"] {
+                step.args.extend(["--".to_string(), "--check".to_string()]);
+            }
+        }
+        let custom_steps = self.custom_steps()?;
+        if steps.is_empty() && custom_steps.is_empty() {
+            outln!(
+                "⚠️  No Cargo.toml, pyproject.toml or package.json found; there are no checks to run"
+            );
+            return Err(CheckFailed("No checks to run".to_string()).into());
+        }
+        let default_count = steps.len();
+        let stages: Vec<CheckStep> = steps.iter().chain(&custom_steps).cloned().collect();
+
+        let (failed, skipped): (Vec<&str>, usize) = std::thread::scope(|scope| {
+            let runs: Vec<_> = stages
+                .iter()
+                .map(|step| (step, scope.spawn(move || self.run_prefixed_stage(step))))
+                .collect();
+
+            let mut failed = Vec::new();
+            let mut skipped = 0;
+            for (index, (step, run)) in runs.into_iter().enumerate() {
+                match run.join() {
+                    Ok(Ok(())) => {}
+                    // Only explicitly requested custom steps fail when missing
+                    Ok(Err(e)) if index < default_count && is_not_found(&e) => {
+                        outln!(
+                            "⏭️  {} skipped: {} is not installed",
+                            step.name,
+                            step.program
+                        );
+                        skipped += 1;
+                    }
+                    Ok(Err(e)) => {
+                        eoutln!("❌ {} failed: {}", step.name, e);
+                        failed.push(step.name.as_str());
+                    }
+                    Err(_) => failed.push(step.name.as_str()),
                 }
-            });
+            }
+            (failed, skipped)
+        });
 
-            // Wait for command to complete
-            let status = child.wait()?;
+        if skipped == stages.len() {
+            return Err(no_checks_ran(&steps));
+        }
 
-            if !status.success() {
-                error!("{} failed", stage);
-                println!("❌ {} failed", stage);
-                return Err(anyhow::anyhow!("{} failed", stage));
+        if !failed.is_empty() {
+            return Err(CheckFailed(format!(
+                "{} of {} check stages failed: {}",
+                failed.len(),
+                stages.len(),
+                failed.join(", ")
+            ))
+            .into());
+        }
+
+        outln!("🎉 All project checks completed successfully!");
+        Ok(())
+    }
+
+    /// Extra steps declared in `.fargin/checks.toml`, in file order
+    ///
+    /// ```toml
+    /// [[checks]]
+    /// name = "audit"
+    /// command = "cargo"
+    /// args = ["audit"]
+    /// ```
+    pub fn custom_steps(&self) -> Result<Vec<CheckStep>> {
+        #[derive(Deserialize)]
+        struct ChecksFile {
+            #[serde(default)]
+            checks: Vec<CheckStep>,
+        }
+
+        let path = fargin_dir(&self.project_root).join("checks.toml");
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file: ChecksFile = toml::from_str(&content)
+            .with_context(|| format!("Invalid check steps in {}", path.display()))?;
+        Ok(file.checks)
+    }
+
+    /// Run one step, prefixing each output line with the step name
+    fn run_prefixed_stage(&self, step: &CheckStep) -> Result<()> {
+        use std::io::{BufRead, BufReader};
+        use std::process::Stdio;
+
+        let mut child = isolate_for_timeout(&mut Command::new(&step.program), self.timeout)
+            .args(&step.args)
+            .current_dir(&self.project_root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to start {}", step.program))?;
+
+        let stdout = child.stdout.take().expect("Failed to capture stdout");
+        let stderr = child.stderr.take().expect("Failed to capture stderr");
+
+        // The readers are detached from the wait so a timeout can still kill the child
+        let stage = step.name.clone();
+        let stdout_reader = std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                outln!("[{}] {}", stage, line);
             }
+        });
+        let stage = step.name.clone();
+        let stderr_reader = std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                eprintln!("[{}] {}", stage, line);
+            }
+        });
 
-            info!("{} passed", stage);
-            println!("✅ {} passed", stage);
+        let status = wait_with_timeout(&mut child, self.timeout, &step.name)?;
+        let _ = stdout_reader.join();
+        let _ = stderr_reader.join();
+
+        if status.success() {
+            outln!("✅ {} passed", step.name);
             Ok(())
+        } else {
+            Err(CheckFailed(format!("{} exited with {}", step.program, status)).into())
         }
+    }
 
-        // Ensure we're in the correct directory
-        std::env::set_current_dir(&self.project_root)?;
+    /// Run `cargo fmt --check` and collect the files and lines that would change
+    pub fn check_formatting_diff(&self) -> Result<FormatDiffReport> {
         info!(
-            "Changed working directory to: {}",
+            "Checking formatting diff in {}",
             self.project_root.display()
         );
 
-        // Run cargo fmt
-        let mut fmt_cmd = std::process::Command::new("cargo");
-        fmt_cmd.arg("fmt");
-        run_command_with_streaming(&mut fmt_cmd, "Cargo Formatting Check".to_string())?;
+        let output = std::process::Command::new(&self.cargo_bin)
+            .args(["fmt", "--", "--check", "--color", "never"])
+            .current_dir(&self.project_root)
+            .output()?;
 
-        // Run cargo clippy
-        let mut clippy_cmd = std::process::Command::new("cargo");
-        clippy_cmd.args(["clippy", "--", "-D", "warnings"]);
-        run_command_with_streaming(&mut clippy_cmd, "Cargo Clippy Linting".to_string())?;
+        let diff = String::from_utf8_lossy(&output.stdout).into_owned();
+        let files = parse_fmt_diff(&diff);
 
-        // Run tests
-        let mut test_cmd = std::process::Command::new("cargo");
-        test_cmd.arg("test");
-        run_command_with_streaming(&mut test_cmd, "Cargo Test Suite".to_string())?;
+        if !output.status.success() && files.is_empty() {
+            return Err(anyhow::anyhow!(
+                "cargo fmt failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
 
-        info!("All project checks completed successfully");
-        println!("🎉 All project checks completed successfully!");
-        Ok(())
+        Ok(FormatDiffReport { files, diff })
+    }
+
+    /// Run clippy and count the warnings it emits per lint
+    pub fn check_clippy(&self) -> Result<ClippyReport> {
+        info!("Running clippy in {}", self.project_root.display());
+
+        let mut cmd = Command::new(&self.cargo_bin);
+        cmd.args(["clippy", "--message-format=json"])
+            .current_dir(&self.project_root);
+        let output = output_with_timeout(&mut cmd, self.timeout, "cargo clippy")?;
+
+        let mut report = parse_clippy_messages(&String::from_utf8_lossy(&output.stdout));
+        report.succeeded = output.status.success();
+        Ok(report)
+    }
+
+    /// Run `cargo fmt` with the configured cargo binary, applying any changes
+    pub fn run_fmt(&self) -> Result<std::process::Output> {
+        self.cargo_output("fmt", "cargo fmt")
+    }
+
+    /// Run `cargo test` with the configured cargo binary
+    pub fn run_tests(&self) -> Result<std::process::Output> {
+        self.cargo_output("test", "cargo test")
+    }
+
+    fn cargo_output(&self, subcommand: &str, stage: &str) -> Result<std::process::Output> {
+        info!("Running {} in {}", stage, self.project_root.display());
+
+        let mut cmd = Command::new(&self.cargo_bin);
+        cmd.arg(subcommand).current_dir(&self.project_root);
+        output_with_timeout(&mut cmd, self.timeout, stage)
     }
 
     /// Check the health and status of project features
-    pub fn check_feature_health(&self) -> Result<FeatureHealthReport> {
-        let features_dir = self.project_root.join(".fargin/features");
-
-        if !features_dir.exists() {
-            return Ok(FeatureHealthReport {
-                total_features: 0,
-                status_distribution: HashMap::new(),
-                stale_features: Vec::new(),
-            });
-        }
+    ///
+    /// Features are read through `FeatureManager`. Those not updated for more
+    /// than `stale_after_days` are reported as stale. The feature's recorded
+    /// `updated_at` is used when present, since file modification times also
+    /// change on checkouts and copies.
+    pub fn check_feature_health(&self, stale_after_days: u64) -> Result<FeatureHealthReport> {
+        let manager = FeatureManager::open(&self.project_root)?;
+        let features = manager.list_features(None, None, None, None);
 
-        let mut total_features = 0;
         let mut status_distribution = HashMap::new();
         let mut stale_features = Vec::new();
+        for feature in &features {
+            *status_distribution.entry(feature.status).or_insert(0) += 1;
 
-        for entry in fs::read_dir(features_dir)? {
-            let entry = entry?;
-            if entry.path().extension().and_then(|s| s.to_str()) == Some("md") {
-                let content = fs::read_to_string(entry.path())?;
-
-                // Basic parsing of feature status
-                let status = if content.contains("Status: Implemented") {
-                    FeatureStatus::Implemented
-                } else if content.contains("Status: InProgress") {
-                    FeatureStatus::InProgress
-                } else if content.contains("Status: Blocked") {
-                    FeatureStatus::Blocked
-                } else {
-                    FeatureStatus::Proposed
-                };
-
-                // Count status distribution
-                *status_distribution.entry(status).or_insert(0) += 1;
-                total_features += 1;
-
-                // Check for stale features (no updates in 30 days)
-                if let Ok(metadata) = entry.metadata() {
-                    if let Ok(modified) = metadata.modified() {
-                        let days_since_update = SystemTime::now()
-                            .duration_since(modified)
-                            .map(|d| d.as_secs() / (24 * 3600))
-                            .unwrap_or(0);
-
-                        if days_since_update > 30 {
-                            stale_features.push(entry.file_name().to_string_lossy().into_owned());
-                        }
-                    }
-                }
+            let days_since_update = (Utc::now() - feature.updated_at).num_days();
+            if days_since_update > stale_after_days as i64 {
+                stale_features.push(format!("{}.md", feature.id));
             }
         }
 
         Ok(FeatureHealthReport {
-            total_features,
+            total_features: features.len(),
             status_distribution,
             stale_features,
         })
@@ -193,26 +552,25 @@ impl ProjectChecker {
 
     /// Check project file structure and recommended directories
     pub fn check_file_structure(&self) -> Result<FileStructureReport> {
-        let recommended_dirs = vec![
-            ".fargin",
-            ".fargin/features",
-            ".fargin/docs",
-            ".fargin/templates",
-            ".fargin/artifacts",
-            "src",
-            "tests",
-            "docs",
-        ];
+        let fargin_dir = fargin_dir(&self.project_root);
+        let meta_dir = fargin_dir
+            .strip_prefix(&self.project_root)
+            .unwrap_or(&fargin_dir)
+            .to_path_buf();
+
+        let mut recommended_dirs = vec![meta_dir.clone()];
+        recommended_dirs.extend(FARGIN_SUBDIRS.iter().map(|dir| meta_dir.join(dir)));
+        recommended_dirs.extend(["src", "tests", "docs"].iter().map(PathBuf::from));
 
         let mut missing_dirs = Vec::new();
         let mut existing_dirs = Vec::new();
 
         for dir in recommended_dirs {
-            let full_path = self.project_root.join(dir);
+            let full_path = self.project_root.join(&dir);
             if full_path.exists() {
-                existing_dirs.push(dir.to_string());
+                existing_dirs.push(dir.display().to_string());
             } else {
-                missing_dirs.push(dir.to_string());
+                missing_dirs.push(dir.display().to_string());
             }
         }
 
@@ -227,42 +585,176 @@ impl ProjectChecker {
         let cargo_toml_path = self.project_root.join("Cargo.toml");
 
         if !cargo_toml_path.exists() {
-            return Ok(DependencyHealthReport {
-                total_dependencies: 0,
-                outdated_dependencies: Vec::new(),
-            });
+            return Ok(DependencyHealthReport::default());
+        }
+
+        let manifest: toml::Table = fs::read_to_string(&cargo_toml_path)?
+            .parse()
+            .with_context(|| format!("Failed to parse {}", cargo_toml_path.display()))?;
+
+        let mut dependency_names = Vec::new();
+        for table in ["dependencies", "dev-dependencies", "build-dependencies"] {
+            if let Some(dependencies) = manifest.get(table).and_then(|value| value.as_table()) {
+                dependency_names.extend(dependencies.keys().cloned());
+            }
         }
 
-        // This is a placeholder. In a real implementation, you'd parse Cargo.toml
-        // and potentially use `cargo outdated` to check for updates
+        let outdated_dependencies = if self.include_outdated {
+            self.check_outdated()?
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         Ok(DependencyHealthReport {
-            total_dependencies: 0,
-            outdated_dependencies: Vec::new(),
+            total_dependencies: dependency_names.len(),
+            outdated_dependencies,
+            dependency_names,
         })
     }
 
+    /// Find dependencies with newer releases using `cargo outdated`
+    ///
+    /// Returns an empty list when the `cargo-outdated` subcommand is not installed.
+    /// This is slow and needs network access, so callers should make it opt-in.
+    pub fn check_outdated(&self) -> Result<Vec<OutdatedDependency>> {
+        self.run_cargo_outdated(&self.cargo_bin)
+    }
+
+    fn run_cargo_outdated(&self, cargo: &str) -> Result<Vec<OutdatedDependency>> {
+        let available = Command::new(cargo)
+            .args(["outdated", "--version"])
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if !available {
+            info!("cargo-outdated is not installed; skipping outdated dependency check");
+            return Ok(Vec::new());
+        }
+
+        let output = Command::new(cargo)
+            .args(["outdated", "--format", "json", "--root-deps-only"])
+            .current_dir(&self.project_root)
+            .output()?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "cargo outdated failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        parse_cargo_outdated(&String::from_utf8_lossy(&output.stdout))
+    }
+
     /// Check Git repository status
+    ///
+    /// Results are cached for a couple of seconds and reused as long as
+    /// `.git/HEAD` and `.git/index` are unchanged, so polling callers don't
+    /// query git on every invocation.
     pub fn check_git_status(&self) -> Result<GitHealthReport> {
         let git_dir = self.project_root.join(".git");
 
         if !git_dir.exists() {
-            return Ok(GitHealthReport {
-                is_git_repo: false,
-                uncommitted_changes: false,
-                unpushed_commits: false,
-                branch_name: None,
-            });
+            return Ok(GitHealthReport::default());
+        }
+
+        let fingerprint = GitFingerprint::read(&git_dir);
+        if let Some(report) = self.cached_git_status(&fingerprint) {
+            debug!(
+                "Using cached git status for {}",
+                self.project_root.display()
+            );
+            return Ok(report);
         }
 
-        // This is a placeholder. In a real implementation, you'd use git commands
+        let report = self.read_git_status()?;
+
+        if let Ok(mut cache) = git_status_cache().lock() {
+            cache.insert(
+                self.project_root.clone(),
+                CachedGitStatus {
+                    fingerprint,
+                    cached_at: Instant::now(),
+                    report: report.clone(),
+                },
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// Look up a cached git status that is still fresh for the given repository state
+    fn cached_git_status(&self, fingerprint: &GitFingerprint) -> Option<GitHealthReport> {
+        let cache = git_status_cache().lock().ok()?;
+        let cached = cache.get(&self.project_root)?;
+
+        if cached.fingerprint == *fingerprint && cached.cached_at.elapsed() < GIT_STATUS_CACHE_TTL {
+            Some(cached.report.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Query the repository for its current status with the git CLI
+    fn read_git_status(&self) -> Result<GitHealthReport> {
+        let status = match self.git(&["status", "--porcelain"]) {
+            Ok(Some(output)) => output,
+            Ok(None) => return Ok(GitHealthReport::default()),
+            Err(e) => {
+                warn!("Could not run git, skipping git checks: {}", e);
+                return Ok(GitHealthReport::default());
+            }
+        };
+        let uncommitted_changes = status.lines().filter(|line| !line.is_empty()).count();
+
+        let branch_name = match self.git(&["rev-parse", "--abbrev-ref", "HEAD"])? {
+            Some(branch) if branch != "HEAD" => Some(branch),
+            Some(_) => self
+                .git(&["rev-parse", "--short", "HEAD"])?
+                .map(|commit| format!("detached at {}", commit)),
+            // No commits yet; HEAD still names the unborn branch
+            None => self.git(&["symbolic-ref", "--short", "HEAD"])?,
+        };
+
+        // Without an upstream there is nothing to compare against
+        let unpushed_commits = self
+            .git(&["rev-list", "--count", "@{u}..HEAD"])?
+            .and_then(|count| count.parse().ok())
+            .unwrap_or(0);
+
         Ok(GitHealthReport {
             is_git_repo: true,
-            uncommitted_changes: false,
-            unpushed_commits: false,
-            branch_name: Some("main".to_string()),
+            uncommitted_changes,
+            unpushed_commits,
+            branch_name,
         })
     }
 
+    /// Run a git command in the project root
+    ///
+    /// Returns the trimmed stdout, `None` if git reported an error, and `Err`
+    /// only if git could not be started at all.
+    fn git(&self, args: &[&str]) -> Result<Option<String>> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&self.project_root)
+            .output()?;
+
+        if !output.status.success() {
+            debug!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        ))
+    }
+
     /// Generate a comprehensive project progress summary
     pub fn generate_progress_summary(&self, verbosity: &str) -> Result<String> {
         let health_report = self.run_all_checks()?;
@@ -371,7 +863,7 @@ impl ProjectChecker {
             🔍 Feature Health:\n\
             Total Features: {}\n\
             Feature Status Distribution:\n{}\
-            Stale Features (>30 days):\n{}\n\
+            Stale Features (>{} days):\n{}\n\
             Potential Actions:\n\
               - Review and update stale features\n\
               - Close or reactivate inactive features\n\n\
@@ -396,6 +888,7 @@ impl ProjectChecker {
               3. Maintain consistent Git workflow\n",
             report.feature_health.total_features,
             feature_summary,
+            self.stale_after_days,
             stale_features_details,
             report.dependency_health.total_dependencies,
             outdated_dependencies_details,
@@ -465,16 +958,18 @@ impl ProjectChecker {
         }
 
         // Git Health Recommendations
-        if report.git_health.uncommitted_changes {
-            recommendations.push(
-                "💾 Commit your current changes to maintain a clean working state".to_string(),
-            );
+        if report.git_health.uncommitted_changes > 0 {
+            recommendations.push(format!(
+                "💾 Commit your {} changed files to maintain a clean working state",
+                report.git_health.uncommitted_changes
+            ));
         }
 
-        if report.git_health.unpushed_commits {
-            recommendations.push(
-                "🌐 Push your local commits to keep the remote repository up to date".to_string(),
-            );
+        if report.git_health.unpushed_commits > 0 {
+            recommendations.push(format!(
+                "🌐 Push your {} local commits to keep the remote repository up to date",
+                report.git_health.unpushed_commits
+            ));
         }
 
         // Project Structure Recommendations
@@ -522,7 +1017,7 @@ impl ProjectChecker {
 }
 
 /// Comprehensive project health report
-#[derive(Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ProjectHealthReport {
     pub feature_health: FeatureHealthReport,
     pub file_structure: FileStructureReport,
@@ -531,7 +1026,7 @@ pub struct ProjectHealthReport {
 }
 
 /// Feature health metrics
-#[derive(Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct FeatureHealthReport {
     pub total_features: usize,
     pub status_distribution: HashMap<FeatureStatus, usize>,
@@ -539,28 +1034,521 @@ pub struct FeatureHealthReport {
 }
 
 /// File structure report
-#[derive(Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct FileStructureReport {
     pub existing_dirs: Vec<String>,
     pub missing_dirs: Vec<String>,
 }
 
 /// Dependency health report
-#[derive(Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct DependencyHealthReport {
     pub total_dependencies: usize,
     pub outdated_dependencies: Vec<String>,
+    pub dependency_names: Vec<String>,
 }
 
 /// Git repository health report
-#[derive(Default)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GitHealthReport {
     pub is_git_repo: bool,
-    pub uncommitted_changes: bool,
-    pub unpushed_commits: bool,
+    pub uncommitted_changes: usize,
+    pub unpushed_commits: usize,
     pub branch_name: Option<String>,
 }
 
+/// A project check (formatting, linting, tests, ...) ran and did not pass
+///
+/// Kept distinct from IO and usage errors so the CLI can exit with a
+/// dedicated status code.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct CheckFailed(pub String);
+
+/// Outcome that ends a check loop early
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LoopUntil {
+    /// Stop as soon as the checks pass
+    Success,
+    /// Stop as soon as the checks fail
+    Failure,
+}
+
+/// Why a check loop stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopStop {
+    /// The `--until` outcome was reached after this many iterations
+    Reached(LoopUntil, u64),
+    /// The iteration limit was hit
+    IterationLimit(u64),
+    /// The filesystem watcher shut down after this many iterations
+    WatchClosed(u64),
+}
+
+/// Run `run_checks` repeatedly, sleeping `interval` between iterations
+///
+/// Stops when `until` is reached or after `iterations` runs (0 = no limit),
+/// whichever comes first.
+pub fn run_check_loop(
+    interval: Duration,
+    iterations: u64,
+    until: Option<LoopUntil>,
+    run_checks: impl FnMut() -> Result<()>,
+) -> LoopStop {
+    run_loop_with(iterations, until, run_checks, || {
+        std::thread::sleep(interval);
+        true
+    })
+}
+
+/// Run `run_checks` once, then again whenever a file under `project_path` changes
+///
+/// Bursts of changes within `debounce` of each other trigger a single re-run,
+/// and changes to fargin metadata, `.git` and build or cache directories
+/// are ignored. Stops on the same
+/// conditions as [`run_check_loop`].
+pub fn watch_check_loop(
+    project_path: &Path,
+    debounce: Duration,
+    iterations: u64,
+    until: Option<LoopUntil>,
+    run_checks: impl FnMut() -> Result<()>,
+) -> Result<LoopStop> {
+    use notify::{RecursiveMode, Watcher};
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).context("Failed to start the filesystem watcher")?;
+    watcher
+        .watch(project_path, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", project_path.display()))?;
+
+    Ok(run_loop_with(iterations, until, run_checks, || {
+        outln!("👀 Waiting for changes...");
+        wait_for_change(&rx, project_path, debounce)
+    }))
+}
+
+/// Block until a relevant change arrives, then keep draining events until
+/// none has arrived for `debounce`
+///
+/// Returns false once the watcher has gone away.
+fn wait_for_change(
+    events: &std::sync::mpsc::Receiver<notify::Result<notify::Event>>,
+    project_path: &Path,
+    debounce: Duration,
+) -> bool {
+    loop {
+        match events.recv() {
+            Ok(Ok(event)) if is_relevant_change(&event, project_path) => break,
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => warn!("Filesystem watcher error: {}", e),
+            Err(_) => return false,
+        }
+    }
+
+    // A disconnect here still leaves a pending change worth checking
+    while events.recv_timeout(debounce).is_ok() {}
+    true
+}
+
+/// Directories whose changes never trigger a re-run, wherever they appear
+const WATCH_IGNORED_DIRS: [&str; 5] = [
+    ".git",
+    "target",
+    "node_modules",
+    "__pycache__",
+    ".pytest_cache",
+];
+
+/// Whether a watcher event should trigger a re-run
+fn is_relevant_change(event: &notify::Event, project_path: &Path) -> bool {
+    if event.kind.is_access() {
+        return false;
+    }
+    // Checks write their own state here, which would otherwise re-trigger them
+    let metadata_dir = fargin_dir(Path::new(""));
+    event.paths.iter().any(|path| {
+        let relative = path.strip_prefix(project_path).unwrap_or(path);
+        !relative.starts_with(&metadata_dir)
+            && !relative.components().any(|component| {
+                component
+                    .as_os_str()
+                    .to_str()
+                    .is_some_and(|name| WATCH_IGNORED_DIRS.contains(&name))
+            })
+    })
+}
+
+/// Shared loop driver: `wait` runs between iterations and returning false
+/// ends the loop
+fn run_loop_with(
+    iterations: u64,
+    until: Option<LoopUntil>,
+    mut run_checks: impl FnMut() -> Result<()>,
+    mut wait: impl FnMut() -> bool,
+) -> LoopStop {
+    let mut iteration_count = 0;
+    loop {
+        iteration_count += 1;
+        outln!("\n🕒 Check Iteration {}", iteration_count);
+
+        let outcome = match run_checks() {
+            Ok(_) => {
+                outln!("✅ Project checks completed successfully");
+                LoopUntil::Success
+            }
+            Err(e) => {
+                eoutln!("❌ Project checks failed: {}", e);
+                LoopUntil::Failure
+            }
+        };
+
+        if until == Some(outcome) {
+            let reason = match outcome {
+                LoopUntil::Success => "checks passed",
+                LoopUntil::Failure => "checks failed",
+            };
+            outln!(
+                "🏁 Stopping after {} iteration(s): {}.",
+                iteration_count,
+                reason
+            );
+            return LoopStop::Reached(outcome, iteration_count);
+        }
+
+        if iterations > 0 && iteration_count >= iterations {
+            outln!("🏁 Reached maximum iterations. Stopping.");
+            return LoopStop::IterationLimit(iteration_count);
+        }
+
+        if !wait() {
+            outln!("🏁 Stopped watching for changes.");
+            return LoopStop::WatchClosed(iteration_count);
+        }
+    }
+}
+
+/// Start `cmd` in its own process group when it may be killed on timeout
+///
+/// On unix this lets [`wait_with_timeout`] kill everything the command
+/// started, such as the rustc and test processes under cargo. Commands without
+/// a timeout stay in the terminal's group so Ctrl-C still reaches them.
+pub fn isolate_for_timeout(cmd: &mut Command, timeout: Option<Duration>) -> &mut Command {
+    #[cfg(unix)]
+    if timeout.is_some() {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    #[cfg(not(unix))]
+    let _ = timeout;
+    cmd
+}
+
+/// Wait for `child`, killing it once `timeout` has elapsed
+///
+/// A timed-out child is reported as a `CheckFailed` naming `stage`. If the
+/// child was started with [`isolate_for_timeout`], its whole process group is
+/// killed.
+pub fn wait_with_timeout(
+    child: &mut std::process::Child,
+    timeout: Option<Duration>,
+    stage: &str,
+) -> Result<std::process::ExitStatus> {
+    let Some(timeout) = timeout else {
+        return Ok(child.wait()?);
+    };
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if started.elapsed() >= timeout {
+            warn!("{} exceeded {:?}, killing it", stage, timeout);
+            // The child may exit between try_wait and kill; either way it is reaped below
+            #[cfg(unix)]
+            // SAFETY: kill only sends a signal. A negative pid names the group led
+            // by the child, and fails harmlessly if the child leads no group
+            unsafe {
+                libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+            }
+            let _ = child.kill();
+            child.wait()?;
+            return Err(CheckFailed(format!(
+                "{} timed out after {}s",
+                stage,
+                timeout.as_secs_f64()
+            ))
+            .into());
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Run `cmd`, copying its stdout and stderr line by line into the given sinks
+///
+/// One thread per pipe writes directly to its sink; both are joined after the
+/// child exits, so every line is written before this returns. The sinks are
+/// handed back for callers that buffer output.
+fn stream_command<O, E>(
+    cmd: &mut Command,
+    stage: &str,
+    timeout: Option<Duration>,
+    mut stdout_sink: O,
+    mut stderr_sink: E,
+) -> Result<(std::process::ExitStatus, O, E)>
+where
+    O: std::io::Write + Send + 'static,
+    E: std::io::Write + Send + 'static,
+{
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let mut child = isolate_for_timeout(cmd, timeout)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = child.stdout.take().expect("Failed to capture stdout");
+    let stderr = child.stderr.take().expect("Failed to capture stderr");
+
+    let stdout_stage = stage.to_string();
+    let stdout_thread = std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let _ = writeln!(stdout_sink, "{}", line);
+            debug!("{} stdout: {}", stdout_stage, line);
+        }
+        let _ = stdout_sink.flush();
+        stdout_sink
+    });
+
+    let stderr_stage = stage.to_string();
+    let stderr_thread = std::thread::spawn(move || {
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            let _ = writeln!(stderr_sink, "{}", line);
+            warn!("{} stderr: {}", stderr_stage, line);
+        }
+        let _ = stderr_sink.flush();
+        stderr_sink
+    });
+
+    // On timeout the reader threads are left to finish on their own
+    let status = wait_with_timeout(&mut child, timeout, stage)?;
+    let stdout_sink = stdout_thread
+        .join()
+        .map_err(|_| anyhow::anyhow!("{} stdout reader panicked", stage))?;
+    let stderr_sink = stderr_thread
+        .join()
+        .map_err(|_| anyhow::anyhow!("{} stderr reader panicked", stage))?;
+
+    Ok((status, stdout_sink, stderr_sink))
+}
+
+/// Like `Command::output`, but kills the command once `timeout` has elapsed
+pub fn output_with_timeout(
+    cmd: &mut Command,
+    timeout: Option<Duration>,
+    stage: &str,
+) -> Result<std::process::Output> {
+    use std::io::Read;
+    use std::process::Stdio;
+
+    let mut child = isolate_for_timeout(cmd, timeout)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stdout_pipe = child.stdout.take().expect("Failed to capture stdout");
+    let mut stderr_pipe = child.stderr.take().expect("Failed to capture stderr");
+
+    // Drain both pipes while waiting so a chatty child can't block on a full pipe
+    let stdout = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout_pipe.read_to_end(&mut buf);
+        buf
+    });
+    let stderr = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stderr_pipe.read_to_end(&mut buf);
+        buf
+    });
+
+    let status = wait_with_timeout(&mut child, timeout, stage)?;
+
+    Ok(std::process::Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// A dependency with a newer release than the version in use
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutdatedDependency {
+    pub name: String,
+    pub current: String,
+    pub latest: String,
+}
+
+impl std::fmt::Display for OutdatedDependency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.name, self.current, self.latest)
+    }
+}
+
+/// Parse the JSON lines printed by `cargo outdated --format json`
+///
+/// Workspaces print one JSON object per member crate.
+fn parse_cargo_outdated(output: &str) -> Result<Vec<OutdatedDependency>> {
+    let mut outdated = Vec::new();
+
+    for line in output
+        .lines()
+        .filter(|line| line.trim_start().starts_with('{'))
+    {
+        let report: serde_json::Value =
+            serde_json::from_str(line).context("Invalid cargo outdated output")?;
+        let dependencies = report["dependencies"].as_array().into_iter().flatten();
+
+        for dependency in dependencies {
+            let field = |key: &str| dependency[key].as_str().unwrap_or_default().to_string();
+            outdated.push(OutdatedDependency {
+                name: field("name"),
+                current: field("project"),
+                latest: field("latest"),
+            });
+        }
+    }
+
+    Ok(outdated)
+}
+
+/// Clippy diagnostics grouped by lint name
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClippyReport {
+    /// Whether clippy itself completed without errors
+    pub succeeded: bool,
+
+    /// Warning count per lint, e.g. `clippy::needless_return`
+    pub warnings: BTreeMap<String, usize>,
+}
+
+impl ClippyReport {
+    /// Total number of warnings across all lints
+    pub fn total_warnings(&self) -> usize {
+        self.warnings.values().sum()
+    }
+
+    /// Whether clippy succeeded without a single warning
+    pub fn passed(&self) -> bool {
+        self.succeeded && self.warnings.is_empty()
+    }
+
+    /// Table of lints, most frequent first
+    pub fn generate_summary(&self) -> String {
+        let mut lints: Vec<(&String, &usize)> = self.warnings.iter().collect();
+        lints.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        let mut summary = format!("   {} warning(s)\n", self.total_warnings());
+        for (lint, count) in lints {
+            summary.push_str(&format!("   {:>5}  {}\n", count, lint));
+        }
+        summary
+    }
+}
+
+/// Count warnings in `cargo clippy --message-format=json` output
+///
+/// Lines that are not compiler messages, and the trailing "N warnings
+/// emitted" summaries, which carry no lint code, are ignored.
+pub fn parse_clippy_messages(output: &str) -> ClippyReport {
+    let mut report = ClippyReport::default();
+
+    for line in output.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if value["reason"] != "compiler-message" || value["message"]["level"] != "warning" {
+            continue;
+        }
+        if let Some(lint) = value["message"]["code"]["code"].as_str() {
+            *report.warnings.entry(lint.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    report
+}
+
+/// Formatting changes `cargo fmt` would make
+#[derive(Debug, Default)]
+pub struct FormatDiffReport {
+    pub files: Vec<FileFormatDiff>,
+    pub diff: String,
+}
+
+/// Lines in a single file that `cargo fmt` would change
+#[derive(Debug, PartialEq)]
+pub struct FileFormatDiff {
+    pub file: String,
+    pub lines: Vec<usize>,
+}
+
+impl FormatDiffReport {
+    /// Whether the project is already formatted
+    pub fn is_clean(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Generate a per-file summary of formatting changes
+    pub fn generate_summary(&self) -> String {
+        let mut summary = String::new();
+        for file in &self.files {
+            let lines = file
+                .lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            summary.push_str(&format!("   {} (lines {})\n", file.file, lines));
+        }
+        summary
+    }
+}
+
+/// Parse rustfmt's `--check` output into per-file line lists
+///
+/// Understands both the `Diff in <file>:<line>:` and the older
+/// `Diff in <file> at line <line>:` headers.
+fn parse_fmt_diff(output: &str) -> Vec<FileFormatDiff> {
+    let mut files: Vec<FileFormatDiff> = Vec::new();
+
+    for header in output
+        .lines()
+        .filter_map(|line| line.strip_prefix("Diff in "))
+    {
+        let header = header.trim_end().trim_end_matches(':');
+        let location = header
+            .rsplit_once(" at line ")
+            .or_else(|| header.rsplit_once(':'));
+
+        let Some((file, line)) = location else {
+            continue;
+        };
+        let Ok(line) = line.parse::<usize>() else {
+            continue;
+        };
+
+        match files.iter_mut().find(|entry| entry.file == file) {
+            Some(entry) => entry.lines.push(line),
+            None => files.push(FileFormatDiff {
+                file: file.to_string(),
+                lines: vec![line],
+            }),
+        }
+    }
+
+    files
+}
+
 /// Detailed project health report formatter
 impl ProjectHealthReport {
     /// Generate a human-readable health report
@@ -633,9 +1621,73 @@ impl ProjectHealthReport {
     }
 }
 
+impl ProjectHealthReport {
+    /// Render the report as metrics in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut metrics = String::new();
+
+        metrics.push_str("# HELP fargin_features_total Number of features by status.\n");
+        metrics.push_str("# TYPE fargin_features_total gauge\n");
+        // Emit every status so series don't disappear when a column empties
+        for status in [
+            FeatureStatus::Proposed,
+            FeatureStatus::InProgress,
+            FeatureStatus::Implemented,
+            FeatureStatus::Blocked,
+            FeatureStatus::Deprecated,
+        ] {
+            metrics.push_str(&format!(
+                "fargin_features_total{{status=\"{}\"}} {}\n",
+                format!("{:?}", status).to_lowercase(),
+                self.feature_health
+                    .status_distribution
+                    .get(&status)
+                    .cloned()
+                    .unwrap_or(0)
+            ));
+        }
+
+        let gauges = [
+            (
+                "fargin_features_stale",
+                "Features without updates in the stale window.",
+                self.feature_health.stale_features.len(),
+            ),
+            (
+                "fargin_dependencies_total",
+                "Declared project dependencies.",
+                self.dependency_health.total_dependencies,
+            ),
+            (
+                "fargin_dependencies_outdated",
+                "Dependencies with newer versions available.",
+                self.dependency_health.outdated_dependencies.len(),
+            ),
+            (
+                "fargin_git_uncommitted",
+                "Uncommitted changes in the working tree.",
+                self.git_health.uncommitted_changes,
+            ),
+            (
+                "fargin_directories_missing",
+                "Recommended project directories that are missing.",
+                self.file_structure.missing_dirs.len(),
+            ),
+        ];
+        for (name, help, value) in gauges {
+            metrics.push_str(&format!(
+                "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"
+            ));
+        }
+
+        metrics
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::NewFeature;
     use tempfile::tempdir;
 
     #[test]
@@ -661,4 +1713,747 @@ mod tests {
             .missing_dirs
             .contains(&".fargin".to_string()));
     }
+
+    #[test]
+    fn test_parse_fmt_diff() {
+        let output = "Diff in /work/src/main.rs:1:\n\
+                      -fn main(){}\n\
+                      +fn main() {}\n\
+                      Diff in /work/src/lib.rs at line 4:\n\
+                      -use  std::fs;\n\
+                      +use std::fs;\n\
+                      Diff in /work/src/main.rs:12:\n\
+                      -let x=1;\n\
+                      +let x = 1;\n";
+
+        assert_eq!(
+            parse_fmt_diff(output),
+            vec![
+                FileFormatDiff {
+                    file: "/work/src/main.rs".to_string(),
+                    lines: vec![1, 12],
+                },
+                FileFormatDiff {
+                    file: "/work/src/lib.rs".to_string(),
+                    lines: vec![4],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_git_status_cache_invalidated_by_index_change() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let git_dir = temp_dir.path().join(".git");
+        fs::create_dir_all(&git_dir)?;
+        fs::write(git_dir.join("HEAD"), "ref: refs/heads/main\n")?;
+
+        let checker = ProjectChecker::new(temp_dir.path());
+        checker.check_git_status()?;
+
+        let fingerprint = GitFingerprint::read(&git_dir);
+        assert!(checker.cached_git_status(&fingerprint).is_some());
+
+        fs::write(git_dir.join("index"), "")?;
+        let fingerprint = GitFingerprint::read(&git_dir);
+        assert!(checker.cached_git_status(&fingerprint).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_health_report_to_prometheus() {
+        let mut report = ProjectHealthReport::default();
+        report
+            .feature_health
+            .status_distribution
+            .insert(FeatureStatus::Implemented, 12);
+        report.feature_health.stale_features = vec!["old.md".to_string(); 3];
+        report.dependency_health.total_dependencies = 40;
+        report.git_health.uncommitted_changes = 5;
+
+        let metrics = report.to_prometheus();
+
+        assert!(metrics.contains("fargin_features_total{status=\"implemented\"} 12\n"));
+        assert!(metrics.contains("fargin_features_total{status=\"proposed\"} 0\n"));
+        assert!(metrics.contains("fargin_features_stale 3\n"));
+        assert!(metrics.contains("fargin_dependencies_total 40\n"));
+        assert!(metrics.contains("fargin_git_uncommitted 5\n"));
+        assert!(metrics.contains("# TYPE fargin_features_stale gauge\n"));
+    }
+
+    #[test]
+    fn test_git_status_reports_staged_file() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .output()
+        };
+        if git(&["init", "-q", "-b", "trunk"]).is_err() {
+            // git is not installed here
+            return Ok(());
+        }
+
+        fs::write(temp_dir.path().join("notes.txt"), "draft")?;
+        git(&["add", "notes.txt"])?;
+
+        let report = ProjectChecker::new(temp_dir.path()).read_git_status()?;
+
+        assert!(report.is_git_repo);
+        assert_eq!(report.branch_name.as_deref(), Some("trunk"));
+        assert_eq!(report.uncommitted_changes, 1);
+        assert_eq!(report.unpushed_commits, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_dependencies_counts_manifest_tables() -> Result<()> {
+        let temp_dir = tempdir()?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            r#"[package]
+name = "fixture"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+anyhow = "1.0"
+log = "0.4"
+
+[dev-dependencies]
+tempfile = "3"
+proptest = "1"
+"#,
+        )?;
+
+        let report = ProjectChecker::new(temp_dir.path()).check_dependencies()?;
+
+        assert_eq!(report.total_dependencies, 5);
+        assert!(report.dependency_names.contains(&"serde".to_string()));
+        assert!(report.dependency_names.contains(&"proptest".to_string()));
+        assert!(report.outdated_dependencies.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_outdated_without_cargo_outdated() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let checker = ProjectChecker::new(temp_dir.path());
+
+        let outdated = checker.run_cargo_outdated("fargin-test-missing-cargo")?;
+
+        assert!(outdated.is_empty());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_dependencies_lists_outdated_when_enabled() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempdir()?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n\n[dependencies]\ntoml = \"0.7\"\n",
+        )?;
+        let fake_cargo = temp_dir.path().join("fake-cargo");
+        fs::write(
+            &fake_cargo,
+            r#"#!/bin/sh
+[ "$2" = "--version" ] && exit 0
+echo '{"crate_name":"demo","dependencies":[{"name":"toml","project":"0.7.8","compat":"---","latest":"0.8.19","kind":"Normal","platform":null}]}'
+"#,
+        )?;
+        fs::set_permissions(&fake_cargo, fs::Permissions::from_mode(0o755))?;
+        let checker =
+            ProjectChecker::new(temp_dir.path()).with_cargo_bin(fake_cargo.display().to_string());
+
+        assert!(checker
+            .check_dependencies()?
+            .outdated_dependencies
+            .is_empty());
+
+        let report = checker.with_outdated_dependencies(true).run_all_checks()?;
+        assert_eq!(
+            report.dependency_health.outdated_dependencies,
+            vec!["toml: 0.7.8 -> 0.8.19".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_cargo_outdated() -> Result<()> {
+        let output = r#"{"crate_name":"demo","dependencies":[{"name":"toml","project":"0.7.8","compat":"---","latest":"0.8.19","kind":"Normal","platform":null}]}"#;
+
+        assert_eq!(
+            parse_cargo_outdated(output)?,
+            vec![OutdatedDependency {
+                name: "toml".to_string(),
+                current: "0.7.8".to_string(),
+                latest: "0.8.19".to_string(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_project_checks_keeps_working_directory() -> Result<()> {
+        let first = tempdir()?;
+        let second = tempdir()?;
+        let before = std::env::current_dir()?;
+
+        // Both directories have an empty, invalid manifest, so the checks fail early
+        for dir in [&first, &second] {
+            fs::write(dir.path().join("Cargo.toml"), "")?;
+        }
+        std::thread::scope(|scope| {
+            let runs = [first.path(), second.path()].map(|path| {
+                scope.spawn(move || ProjectChecker::new(path).run_project_checks().is_err())
+            });
+            for run in runs {
+                assert!(run.join().unwrap());
+            }
+        });
+
+        assert_eq!(std::env::current_dir()?, before);
+        Ok(())
+    }
+
+    #[test]
+    fn test_watch_ignores_metadata_and_cache_dirs() {
+        use notify::event::{EventKind, ModifyKind};
+
+        let project = Path::new("/work/demo");
+        let changed = |path: &str| {
+            let event =
+                notify::Event::new(EventKind::Modify(ModifyKind::Any)).add_path(project.join(path));
+            is_relevant_change(&event, project)
+        };
+
+        assert!(changed("src/lib.rs"));
+        assert!(changed("tests/fixtures/target.txt"));
+        assert!(!changed(".fargin/history/undo.jsonl"));
+        assert!(!changed("target/debug/build.log"));
+        assert!(!changed("web/node_modules/left-pad/index.js"));
+        assert!(!changed("pkg/__pycache__/mod.cpython-312.pyc"));
+        assert!(!changed(".pytest_cache/v/cache/lastfailed"));
+    }
+
+    #[test]
+    fn test_parallel_checks_report_every_failed_stage() -> Result<()> {
+        let temp_dir = tempdir()?;
+        fs::create_dir_all(temp_dir.path().join("src"))?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"two_stages_fail\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+        )?;
+        // Tested, but left unformatted and `x == x` trips clippy's deny-by-default eq_op lint
+        fs::write(
+            temp_dir.path().join("src/lib.rs"),
+            "pub fn same(x: i32) -> bool { x == x }\n",
+        )?;
+
+        let error = ProjectChecker::new(temp_dir.path())
+            .run_project_checks_parallel()
+            .expect_err("fmt and clippy should fail");
+        let message = error.to_string();
+
+        assert!(message.contains("Cargo Formatting Check"), "{}", message);
+        assert!(message.contains("Cargo Clippy Linting"), "{}", message);
+        assert!(!message.contains("Cargo Test Suite"), "{}", message);
+        Ok(())
+    }
+
+    #[test]
+    fn test_health_report_json_round_trip() -> Result<()> {
+        let mut report = ProjectHealthReport::default();
+        report.feature_health.total_features = 3;
+        report
+            .feature_health
+            .status_distribution
+            .insert(FeatureStatus::InProgress, 2);
+        report
+            .feature_health
+            .status_distribution
+            .insert(FeatureStatus::Blocked, 1);
+        report.dependency_health.dependency_names = vec!["serde".to_string()];
+        report.git_health.branch_name = Some("main".to_string());
+
+        let json = serde_json::to_string_pretty(&report)?;
+        assert!(json.contains("\"InProgress\": 2"));
+
+        let parsed: ProjectHealthReport = serde_json::from_str(&json)?;
+        assert_eq!(parsed, report);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_feature_threshold() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let features_dir = temp_dir.path().join(".fargin/features");
+        fs::create_dir_all(&features_dir)?;
+
+        for (id, days_ago) in [("fresh", 9), ("stale", 11)] {
+            let updated_at = Utc::now() - chrono::Duration::days(days_ago);
+            fs::write(
+                features_dir.join(format!("{}.md", id)),
+                format!(
+                    "---\nid: {}\nstatus: Proposed\npriority: Medium\n\
+                     created_at: {}\nupdated_at: {}\n---\n# Feature: {}\n",
+                    id,
+                    updated_at.to_rfc3339(),
+                    updated_at.to_rfc3339(),
+                    id
+                ),
+            )?;
+        }
+
+        let checker = ProjectChecker::new(temp_dir.path());
+        let report = checker.check_feature_health(10)?;
+        assert_eq!(report.stale_features, vec!["stale.md".to_string()]);
+
+        // Default window: neither file is old enough
+        let report = checker.run_all_checks()?;
+        assert!(report.feature_health.stale_features.is_empty());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_output_with_timeout_kills_slow_command() {
+        let started = Instant::now();
+        let mut cmd = Command::new("sleep");
+        cmd.arg("10");
+
+        let error =
+            output_with_timeout(&mut cmd, Some(Duration::from_secs(1)), "sleep").unwrap_err();
+
+        assert!(error.is::<CheckFailed>());
+        assert!(error.to_string().contains("sleep timed out after 1s"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_timeout_kills_grandchildren() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let pid_file = temp_dir.path().join("sleep.pid");
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c")
+            .arg(format!("sleep 30 & echo $! > {}; wait", pid_file.display()));
+
+        let error = output_with_timeout(&mut cmd, Some(Duration::from_secs(1)), "sh").unwrap_err();
+        assert!(error.is::<CheckFailed>());
+
+        // The orphaned sleep is gone, or at most a zombie waiting to be reaped
+        let pid = fs::read_to_string(&pid_file)?.trim().to_string();
+        let started = Instant::now();
+        loop {
+            let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap_or_default();
+            let state = stat
+                .rsplit(") ")
+                .next()
+                .and_then(|rest| rest.chars().next());
+            if matches!(state, None | Some('Z') | Some('X')) {
+                break;
+            }
+            assert!(
+                started.elapsed() < Duration::from_secs(5),
+                "sleep {} survived the timeout",
+                pid
+            );
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_output_with_timeout_returns_output() -> Result<()> {
+        let mut cmd = Command::new("echo");
+        cmd.arg("done");
+
+        let output = output_with_timeout(&mut cmd, Some(Duration::from_secs(5)), "echo")?;
+
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "done");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_clippy_messages_groups_by_lint() {
+        let warning = |code: &str| {
+            format!(
+                r#"{{"reason":"compiler-message","message":{{"level":"warning","code":{{"code":"{}"}}}}}}"#,
+                code
+            )
+        };
+        let output = [
+            warning("clippy::needless_return"),
+            r#"{"reason":"compiler-artifact","target":{}}"#.to_string(),
+            warning("unused_variables"),
+            warning("clippy::needless_return"),
+            r#"{"reason":"compiler-message","message":{"level":"warning","code":null}}"#
+                .to_string(),
+            "not json".to_string(),
+        ]
+        .join("\n");
+
+        let report = parse_clippy_messages(&output);
+
+        assert_eq!(report.warnings["clippy::needless_return"], 2);
+        assert_eq!(report.warnings["unused_variables"], 1);
+        assert_eq!(report.total_warnings(), 3);
+        assert!(report
+            .generate_summary()
+            .starts_with("   3 warning(s)\n       2  clippy::needless_return\n"));
+    }
+
+    #[test]
+    fn test_check_clippy_on_fixture_crate() -> Result<()> {
+        let temp_dir = tempdir()?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"clippy_fixture\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+        )?;
+        fs::create_dir(temp_dir.path().join("src"))?;
+        fs::write(
+            temp_dir.path().join("src/main.rs"),
+            "fn one() -> i32 {\n    return 1;\n}\n\nfn two() -> i32 {\n    return 2;\n}\n\nfn main() {\n    println!(\"{}\", one() + two());\n}\n",
+        )?;
+
+        let report = ProjectChecker::new(temp_dir.path()).check_clippy()?;
+
+        assert!(report.succeeded);
+        assert!(!report.passed());
+        assert_eq!(
+            report.warnings,
+            BTreeMap::from([("clippy::needless_return".to_string(), 2)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_python_project_uses_python_toolchain() -> Result<()> {
+        let temp_dir = tempdir()?;
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"demo\"\n",
+        )?;
+
+        let kind = ProjectKind::detect(temp_dir.path());
+        assert_eq!(kind, ProjectKind::Python);
+        let programs: Vec<String> = kind
+            .default_steps("cargo")
+            .into_iter()
+            .map(|step| step.program)
+            .collect();
+        assert_eq!(programs, vec!["ruff", "black", "pytest"]);
+
+        // Whichever tools are installed run; missing ones are skipped
+        if let Err(e) = ProjectChecker::new(temp_dir.path()).run_project_checks() {
+            assert!(e.is::<CheckFailed>(), "unexpected error: {:#}", e);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_tools_are_skipped() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        let missing = CheckStep::new("Imaginary", "fargin-test-no-such-tool", &[]);
+        let present = CheckStep::new("Version", "cargo", &["--version"]);
+
+        ProjectChecker::new(temp_dir.path())
+            .with_steps(vec![missing.clone(), present])
+            .run_project_checks()?;
+        assert_eq!(ProjectKind::detect(temp_dir.path()), ProjectKind::Unknown);
+
+        // Skipping every step is not a pass
+        for run in [
+            ProjectChecker::run_project_checks,
+            ProjectChecker::run_project_checks_parallel,
+        ] {
+            let error =
+                run(&ProjectChecker::new(temp_dir.path()).with_steps(vec![missing.clone()]))
+                    .expect_err("nothing ran");
+            assert!(error.is::<CheckFailed>(), "{:#}", error);
+            assert!(error.to_string().contains("No checks ran"), "{:#}", error);
+        }
+        Ok(())
+    }
+
+    /// A stand-in for `run_project_checks` that fails until `flip_after` runs
+    fn flipping_checks(flip_after: u64, calls: &mut u64) -> Result<()> {
+        *calls += 1;
+        if *calls > flip_after {
+            Ok(())
+        } else {
+            Err(CheckFailed("not yet".to_string()).into())
+        }
+    }
+
+    #[test]
+    fn test_loop_until_success_stops_when_checks_flip() {
+        let mut calls = 0;
+        let stop = run_check_loop(Duration::ZERO, 10, Some(LoopUntil::Success), || {
+            flipping_checks(3, &mut calls)
+        });
+
+        assert_eq!(stop, LoopStop::Reached(LoopUntil::Success, 4));
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn test_loop_until_failure_stops_on_first_failure() {
+        let mut calls = 0;
+        let stop = run_check_loop(Duration::ZERO, 10, Some(LoopUntil::Failure), || {
+            // Passes twice, then breaks
+            calls += 1;
+            if calls <= 2 {
+                Ok(())
+            } else {
+                Err(CheckFailed("broken".to_string()).into())
+            }
+        });
+
+        assert_eq!(stop, LoopStop::Reached(LoopUntil::Failure, 3));
+    }
+
+    #[test]
+    fn test_loop_iteration_limit_bounds_until() {
+        let mut calls = 0;
+        let stop = run_check_loop(Duration::ZERO, 2, Some(LoopUntil::Success), || {
+            flipping_checks(5, &mut calls)
+        });
+
+        assert_eq!(stop, LoopStop::IterationLimit(2));
+        assert_eq!(calls, 2);
+    }
+
+    fn modify_event(path: &str) -> notify::Result<notify::Event> {
+        Ok(
+            notify::Event::new(notify::EventKind::Modify(notify::event::ModifyKind::Any))
+                .add_path(PathBuf::from(path)),
+        )
+    }
+
+    #[test]
+    fn test_watch_reruns_once_per_debounced_burst() {
+        let project = Path::new("/project");
+        let debounce = Duration::from_millis(100);
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        // Ignored paths first, then a burst of saves that should coalesce;
+        // the watcher goes away well after the debounce window
+        let watcher = std::thread::spawn(move || {
+            tx.send(modify_event("/project/target/debug/fargin"))
+                .unwrap();
+            tx.send(modify_event("/project/.git/index")).unwrap();
+            for _ in 0..3 {
+                tx.send(modify_event("/project/src/lib.rs")).unwrap();
+            }
+            std::thread::sleep(debounce * 3);
+        });
+
+        let mut calls = 0;
+        let started = Instant::now();
+        let mut rerun_after = None;
+        let stop = run_loop_with(
+            0,
+            None,
+            || {
+                calls += 1;
+                if calls == 2 {
+                    rerun_after = Some(started.elapsed());
+                }
+                Ok(())
+            },
+            || wait_for_change(&rx, project, debounce),
+        );
+        watcher.join().unwrap();
+
+        // The whole burst triggers one re-run, and only after the debounce window
+        assert_eq!(calls, 2);
+        assert!(rerun_after.unwrap() >= debounce);
+        assert_eq!(stop, LoopStop::WatchClosed(2));
+    }
+
+    #[test]
+    fn test_watch_ignores_git_and_target_changes() {
+        let project = Path::new("/project");
+        let (tx, rx) = std::sync::mpsc::channel();
+        tx.send(modify_event("/project/.git/HEAD")).unwrap();
+        tx.send(modify_event("/project/target/release/app"))
+            .unwrap();
+        drop(tx);
+
+        assert!(!wait_for_change(&rx, project, Duration::from_millis(10)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stream_command_keeps_every_line() -> Result<()> {
+        let mut cmd = Command::new("sh");
+        cmd.args([
+            "-c",
+            "i=1; while [ $i -le 5000 ]; do echo out $i; echo err $i >&2; i=$((i+1)); done",
+        ]);
+
+        let (status, stdout, stderr) =
+            stream_command(&mut cmd, "chatty", None, Vec::new(), Vec::new())?;
+
+        assert!(status.success());
+        let stdout = String::from_utf8(stdout)?;
+        let stderr = String::from_utf8(stderr)?;
+        let expected_out: Vec<String> = (1..=5000).map(|i| format!("out {}", i)).collect();
+        let expected_err: Vec<String> = (1..=5000).map(|i| format!("err {}", i)).collect();
+        assert_eq!(stdout.lines().collect::<Vec<_>>(), expected_out);
+        assert_eq!(stderr.lines().collect::<Vec<_>>(), expected_err);
+        Ok(())
+    }
+
+    #[test]
+    fn test_configured_cargo_bin_is_used_for_checks() -> Result<()> {
+        let temp_dir = tempdir()?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n",
+        )?;
+        let mut config = crate::config::ProjectConfig::new("demo".to_string(), String::new());
+        config.cargo_bin = "cross".to_string();
+        config.save(temp_dir.path())?;
+
+        let programs: Vec<String> = ProjectChecker::new(temp_dir.path())
+            .steps()
+            .into_iter()
+            .map(|step| step.program)
+            .collect();
+        assert_eq!(programs, vec!["cross", "cross", "cross"]);
+
+        // Without a config the usual cargo is used
+        fs::remove_dir_all(temp_dir.path().join(".fargin"))?;
+        let checker = ProjectChecker::new(temp_dir.path());
+        assert!(checker.steps().iter().all(|step| step.program == "cargo"));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_configured_cargo_bin_runs_fmt_clippy_and_test() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempdir()?;
+        let fake_cargo = temp_dir.path().join("fake-cargo");
+        let calls = temp_dir.path().join("calls.log");
+        fs::write(
+            &fake_cargo,
+            format!("#!/bin/sh\necho \"$1\" >> {}\n", calls.display()),
+        )?;
+        fs::set_permissions(&fake_cargo, fs::Permissions::from_mode(0o755))?;
+        let mut config = crate::config::ProjectConfig::new("demo".to_string(), String::new());
+        config.cargo_bin = fake_cargo.display().to_string();
+        config.save(temp_dir.path())?;
+
+        let checker = ProjectChecker::new(temp_dir.path());
+        assert!(checker.run_fmt()?.status.success());
+        checker.check_clippy()?;
+        assert!(checker.run_tests()?.status.success());
+
+        assert_eq!(fs::read_to_string(&calls)?, "fmt\nclippy\ntest\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_progress_summary_uses_parsed_feature_status() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        manager.add_feature(NewFeature {
+            name: "Misleading".to_string(),
+            description: Some("Status: Implemented is what we are aiming for".to_string()),
+            ..Default::default()
+        })?;
+
+        let checker = ProjectChecker::new(temp_dir.path());
+        let report = checker.check_feature_health(DEFAULT_STALE_AFTER_DAYS)?;
+        assert_eq!(report.total_features, 1);
+        assert_eq!(
+            report.status_distribution.get(&FeatureStatus::Proposed),
+            Some(&1)
+        );
+        assert!(!report
+            .status_distribution
+            .contains_key(&FeatureStatus::Implemented));
+
+        let brief = checker.generate_progress_summary("low")?;
+        assert!(brief.contains("1 total (0 implemented)"), "{}", brief);
+
+        let detailed = checker
+            .with_stale_after_days(7)
+            .generate_progress_summary("high")?;
+        assert!(
+            detailed.contains("Stale Features (>7 days):"),
+            "{}",
+            detailed
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_feature_health_counts_each_saved_status() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let statuses = [
+            FeatureStatus::Proposed,
+            FeatureStatus::InProgress,
+            FeatureStatus::Blocked,
+            FeatureStatus::Implemented,
+            FeatureStatus::Deprecated,
+        ];
+        for status in statuses {
+            let id = manager.add_feature(NewFeature {
+                name: format!("{:?} feature", status),
+                description: Some("Mentions Status: Implemented and Status: Blocked".to_string()),
+                ..Default::default()
+            })?;
+            manager.update_feature(
+                &id,
+                crate::features::FeatureUpdateRequest {
+                    status: Some(status),
+                    force: true,
+                    ..Default::default()
+                },
+            )?;
+        }
+
+        // Files from before front matter keep the status in the details list
+        fs::write(
+            fargin_dir(temp_dir.path()).join("features/legacy.md"),
+            "# Feature: Legacy\n\n## Details\n- **ID**: legacy\n- **Status**: Blocked\n",
+        )?;
+
+        let report = ProjectChecker::new(temp_dir.path()).check_feature_health(30)?;
+        assert_eq!(report.total_features, 6);
+        for status in statuses {
+            let expected = if status == FeatureStatus::Blocked {
+                2
+            } else {
+                1
+            };
+            assert_eq!(
+                report.status_distribution.get(&status),
+                Some(&expected),
+                "{:?}",
+                status
+            );
+        }
+
+        Ok(())
+    }
 }
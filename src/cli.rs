@@ -1,5 +1,7 @@
+use crate::config;
 use crate::features;
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use std::io::Write;
 use std::path::PathBuf;
 
 /// Fargin - LLM-driven project development assistant
@@ -11,10 +13,34 @@ use std::path::PathBuf;
     long_about = "A tool to help manage, develop, and optimize projects using large language models"
 )]
 pub struct Cli {
+    /// Only log errors
+    #[arg(short, long, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Log more detail (-v for debug, -vv for trace)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Print plain text without emoji or ANSI colors (also enabled by NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Log level selected by `--quiet`/`--verbose`, before any `RUST_LOG` override
+    pub fn log_level(&self) -> log::LevelFilter {
+        match (self.quiet, self.verbose) {
+            (true, _) => log::LevelFilter::Error,
+            (false, 0) => log::LevelFilter::Info,
+            (false, 1) => log::LevelFilter::Debug,
+            (false, _) => log::LevelFilter::Trace,
+        }
+    }
+}
+
 /// Primary commands for project development workflow
 #[derive(Subcommand)]
 pub enum Commands {
@@ -61,12 +87,35 @@ pub enum Commands {
     /// Reset project state or configurations
     Reset {
         /// Reset scope
-        #[arg(default_value = "soft")]
-        scope: String,
+        #[arg(value_enum, default_value = "soft")]
+        scope: crate::reset::ResetScope,
 
         /// Force reset without confirmation
         #[arg(short, long)]
         force: bool,
+
+        /// Don't back up .fargin before a hard reset
+        #[arg(long, conflicts_with = "backup_dir")]
+        no_backup: bool,
+
+        /// Directory to write the hard reset backup to (default: project path)
+        #[arg(long, value_name = "DIR")]
+        backup_dir: Option<PathBuf>,
+
+        /// Project path (default: current directory)
+        #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
+        path: PathBuf,
+    },
+
+    /// Revert the most recent destructive operation
+    Undo {
+        /// Project path (default: current directory)
+        #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
+        path: PathBuf,
+
+        /// List recorded operations instead of undoing one
+        #[arg(short, long)]
+        list: bool,
     },
 
     /// Provide guidance and best practices
@@ -89,6 +138,39 @@ pub enum Commands {
         /// List all available howto topics
         #[arg(long, short)]
         list_topics: bool,
+
+        /// Project path whose `.fargin/docs/howto` topics are included
+        #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
+        path: PathBuf,
+    },
+
+    /// Read or edit the project configuration
+    Config {
+        /// Subcommand for configuration operations
+        #[command(subcommand)]
+        operation: ConfigOperation,
+
+        /// Project path (default: current directory)
+        #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
+        path: PathBuf,
+    },
+
+    /// Summarize project config, features, progress and git state
+    Status {
+        /// Output format for the status report
+        #[arg(short, long, value_enum, default_value_t = HowtoOutputFormat::Terminal)]
+        output: HowtoOutputFormat,
+
+        /// Project path (default: current directory)
+        #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
+        path: PathBuf,
+    },
+
+    /// Generate shell completion scripts
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
     },
 }
 
@@ -108,7 +190,7 @@ pub enum InitOperation {
         #[arg(long, default_value = "cargo")]
         cargo_bin: String,
 
-        /// Cargo template or preset
+        /// cargo-generate template to start from instead of `cargo new`
         #[arg(short, long)]
         template: Option<String>,
 
@@ -116,9 +198,21 @@ pub enum InitOperation {
         #[arg(short, long, default_value = "true")]
         with_fargin: bool,
 
+        /// Scaffold a CI pipeline that runs fargin checks
+        #[arg(long, value_enum, value_name = "PROVIDER")]
+        with_ci: Option<config::CiProvider>,
+
+        /// Initialize over an existing project and overwrite generated files
+        #[arg(long)]
+        force: bool,
+
         /// Perform a dry run without creating actual files
         #[arg(long)]
         dry_run: bool,
+
+        /// How to print the dry-run plan
+        #[arg(long, value_enum, default_value_t = RunOutputFormat::Terminal, requires = "dry_run")]
+        output: RunOutputFormat,
     },
 
     /// Create a new project from a template
@@ -137,9 +231,21 @@ pub enum InitOperation {
         #[arg(short, long, default_value = "true")]
         with_fargin: bool,
 
+        /// Scaffold a CI pipeline that runs fargin checks
+        #[arg(long, value_enum, value_name = "PROVIDER")]
+        with_ci: Option<config::CiProvider>,
+
+        /// Initialize over an existing project and overwrite generated files
+        #[arg(long)]
+        force: bool,
+
         /// Perform a dry run without creating actual files
         #[arg(long)]
         dry_run: bool,
+
+        /// How to print the dry-run plan
+        #[arg(long, value_enum, default_value_t = RunOutputFormat::Terminal, requires = "dry_run")]
+        output: RunOutputFormat,
     },
 
     /// Create a minimal Fargin project structure
@@ -151,7 +257,7 @@ pub enum InitOperation {
         #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
         path: PathBuf,
 
-        /// Project type (rust, python, js, etc.)
+        /// Project type (rust, python, js, typescript)
         #[arg(short = 't', long, default_value = "rust")]
         project_type: String,
 
@@ -159,9 +265,21 @@ pub enum InitOperation {
         #[arg(short, long)]
         with_fargin: bool,
 
+        /// Scaffold a CI pipeline that runs fargin checks
+        #[arg(long, value_enum, value_name = "PROVIDER")]
+        with_ci: Option<config::CiProvider>,
+
+        /// Initialize over an existing project and overwrite generated files
+        #[arg(long)]
+        force: bool,
+
         /// Perform a dry run without creating actual files
         #[arg(long)]
         dry_run: bool,
+
+        /// How to print the dry-run plan
+        #[arg(long, value_enum, default_value_t = RunOutputFormat::Terminal, requires = "dry_run")]
+        output: RunOutputFormat,
     },
 }
 
@@ -188,29 +306,102 @@ pub enum FeatureOperation {
         /// Assign feature to a specific person/team
         #[arg(short, long)]
         assigned_to: Option<String>,
+
+        /// Acceptance criteria, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        acceptance_criteria: Option<Vec<String>>,
+
+        /// IDs of related features this one depends on, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        related_features: Option<Vec<String>>,
+
+        /// Due date, as YYYY-MM-DD or an RFC 3339 timestamp
+        #[arg(long, value_parser = features::parse_due_date)]
+        due: Option<chrono::DateTime<chrono::Utc>>,
+
+        /// Estimated effort on a 1-10 scale
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=10))]
+        complexity: Option<u8>,
     },
 
     /// List existing features
     List {
+        /// Filter features by tag; several tags can be given comma-separated
+        #[arg(short, long, value_delimiter = ',')]
+        tag: Vec<String>,
+
+        /// With several tags, show features having any of them (the default)
+        #[arg(long)]
+        match_any: bool,
+
+        /// With several tags, show only features having all of them
+        #[arg(long, conflicts_with = "match_any")]
+        match_all: bool,
+
+        /// Filter features by status
+        #[arg(short, long, value_enum)]
+        status: Option<features::FeatureStatus>,
+
+        /// Filter features by priority
+        #[arg(short, long, value_enum)]
+        priority: Option<features::Priority>,
+
+        /// Only show features assigned to this person/team (case-insensitive)
+        #[arg(short, long)]
+        assigned_to: Option<String>,
+
+        /// Sort order for the feature list (default: created)
+        #[arg(long, value_enum)]
+        sort: Option<features::FeatureSort>,
+
+        /// Output format for the feature list
+        #[arg(long, value_enum, default_value_t = HowtoOutputFormat::Terminal)]
+        output: HowtoOutputFormat,
+    },
+
+    /// Walk through features one at a time and update them with single-key actions
+    Triage {
         /// Filter features by tag
         #[arg(short, long)]
         tag: Option<String>,
 
         /// Filter features by status
-        #[arg(short, long, value_enum)]
-        status: Option<features::FeatureStatus>,
+        #[arg(short, long, value_enum, default_value = "proposed")]
+        status: features::FeatureStatus,
 
         /// Filter features by priority
         #[arg(short, long, value_enum)]
         priority: Option<features::Priority>,
     },
 
+    /// Report dependency cycles between features
+    CheckDeps,
+
+    /// Search features by name, tags, description, and acceptance criteria
+    Search {
+        /// Text to look for (case-insensitive)
+        query: String,
+    },
+
+    /// Summarize features by status, priority, assignment, and complexity
+    Stats {
+        /// Output format for the statistics
+        #[arg(long, value_enum, default_value_t = HowtoOutputFormat::Terminal)]
+        output: HowtoOutputFormat,
+    },
+
     /// Show details of a specific feature
     Show {
         /// Feature ID
         id: String,
     },
 
+    /// Show the status changes recorded for a feature
+    History {
+        /// Feature ID
+        id: String,
+    },
+
     /// Update an existing feature
     Update {
         /// Feature ID
@@ -235,6 +426,75 @@ pub enum FeatureOperation {
         /// Reassign feature
         #[arg(short, long)]
         assigned_to: Option<String>,
+
+        /// Replace acceptance criteria, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        acceptance_criteria: Option<Vec<String>>,
+
+        /// Replace related feature IDs, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        related_features: Option<Vec<String>>,
+
+        /// Due date, as YYYY-MM-DD or an RFC 3339 timestamp
+        #[arg(long, value_parser = features::parse_due_date)]
+        due: Option<chrono::DateTime<chrono::Utc>>,
+
+        /// Estimated effort on a 1-10 scale
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=10))]
+        complexity: Option<u8>,
+
+        /// Allow a status change that skips the usual workflow
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Rename a feature, keeping its ID unless --new-id is given
+    Rename {
+        /// Feature ID
+        id: String,
+
+        /// New name
+        new_name: String,
+
+        /// Also move the feature to this ID, updating every reference to it
+        #[arg(long)]
+        new_id: Option<String>,
+    },
+
+    /// Apply the same update to every feature matching the filters
+    #[command(group = clap::ArgGroup::new("updates").required(true).multiple(true))]
+    UpdateAll {
+        /// Only features with this status
+        #[arg(short, long, value_enum)]
+        status: Option<features::FeatureStatus>,
+
+        /// Only features with this tag
+        #[arg(short, long)]
+        tag: Option<String>,
+
+        /// Only features with this priority
+        #[arg(short, long, value_enum)]
+        priority: Option<features::Priority>,
+
+        /// Only features assigned to this person
+        #[arg(short, long)]
+        assigned_to: Option<String>,
+
+        /// New status
+        #[arg(long, value_enum, group = "updates")]
+        set_status: Option<features::FeatureStatus>,
+
+        /// New priority
+        #[arg(long, value_enum, group = "updates")]
+        set_priority: Option<features::Priority>,
+
+        /// New assignee
+        #[arg(long, group = "updates")]
+        set_assigned_to: Option<String>,
+
+        /// Allow status changes that skip the usual workflow
+        #[arg(long)]
+        force: bool,
     },
 
     /// Remove a feature from the project
@@ -243,6 +503,19 @@ pub enum FeatureOperation {
         id: String,
     },
 
+    /// Merge a duplicate feature into another and archive the duplicate
+    Merge {
+        /// ID of the feature to keep
+        keep_id: String,
+
+        /// ID of the duplicate feature to fold in and archive
+        drop_id: String,
+
+        /// Which feature's status and assignee win when they differ
+        #[arg(long, value_enum, default_value = "keep")]
+        prefer: features::MergePreference,
+    },
+
     /// Generate intelligent suggestions for a feature
     Suggest {
         /// Feature ID to generate suggestions for
@@ -252,7 +525,7 @@ pub enum FeatureOperation {
         #[arg(short, long, value_enum)]
         suggestion_type: Option<features::SuggestionType>,
 
-        /// Verbosity of suggestions
+        /// Verbosity of suggestions: low (top high-impact only), normal (all), high (all, with detail)
         #[arg(short, long, default_value = "normal")]
         verbosity: String,
 
@@ -263,6 +536,107 @@ pub enum FeatureOperation {
         /// Save suggestions to a file
         #[arg(long)]
         save_path: Option<PathBuf>,
+
+        /// Show why each suggestion was generated
+        #[arg(long)]
+        explain: bool,
+    },
+
+    /// Raise the priority of one or more features by one level
+    Bump {
+        /// Feature IDs
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
+    /// Lower the priority of one or more features by one level
+    Lower {
+        /// Feature IDs
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
+    /// Manually order a feature relative to another within its status column
+    Rank {
+        /// Feature ID to move
+        id: String,
+
+        /// Place the feature directly above this feature
+        #[arg(
+            long,
+            value_name = "OTHER_ID",
+            conflicts_with = "below",
+            required_unless_present = "below"
+        )]
+        above: Option<String>,
+
+        /// Place the feature directly below this feature
+        #[arg(long, value_name = "OTHER_ID")]
+        below: Option<String>,
+    },
+
+    /// Count features grouped by a field
+    CountBy {
+        /// Field to group by
+        #[arg(value_enum)]
+        field: features::CountField,
+
+        /// Output format for the counts
+        #[arg(long, value_enum, default_value_t = CountOutputFormat::Terminal)]
+        output: CountOutputFormat,
+    },
+
+    /// Print a dependency-respecting order to implement the remaining features
+    ImplementOrder {
+        /// Only consider features with this tag
+        #[arg(short, long)]
+        tag: Option<String>,
+    },
+
+    /// Draw features and how they relate as a diagram
+    Graph {
+        /// Diagram format
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Mermaid)]
+        format: GraphFormat,
+    },
+
+    /// Export features as a single document
+    Export {
+        /// Filter features by tag
+        #[arg(short, long)]
+        tag: Option<String>,
+
+        /// Filter features by status
+        #[arg(short, long, value_enum)]
+        status: Option<features::FeatureStatus>,
+
+        /// Filter features by priority
+        #[arg(short, long, value_enum)]
+        priority: Option<features::Priority>,
+
+        /// Export format
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Markdown)]
+        format: ExportFormat,
+
+        /// Write the export to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Import features from a JSON export
+    Import {
+        /// JSON file written by `fargin feature export --format json`
+        file: PathBuf,
+
+        /// What to do when an imported feature's ID already exists
+        #[arg(long, value_enum, default_value = "skip")]
+        on_conflict: features::ConflictPolicy,
+    },
+
+    /// Create features from a markdown `- [ ] task` checklist
+    ImportChecklist {
+        /// Markdown file containing the checklist
+        file: PathBuf,
     },
 }
 
@@ -287,6 +661,45 @@ pub enum DesignOperation {
         /// Design identifier
         id: String,
     },
+    /// Update an existing design
+    Update {
+        /// Design identifier
+        id: String,
+
+        /// New design status
+        #[arg(short, long, value_enum)]
+        status: Option<crate::design::DesignStatus>,
+
+        /// New design description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Allow status changes outside the normal review lifecycle
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Project configuration operations
+#[derive(Debug, Subcommand)]
+pub enum ConfigOperation {
+    /// Print the value of a config key
+    Get {
+        /// Config key (name, description, goals)
+        key: String,
+    },
+
+    /// Set a config key; list keys take comma-separated values
+    Set {
+        /// Config key (name, description, goals)
+        key: String,
+
+        /// New value
+        value: String,
+    },
+
+    /// Print the whole configuration
+    Show,
 }
 
 /// Check operations for project health and consistency
@@ -300,6 +713,26 @@ pub enum CheckOperation {
         /// Project path (default: current directory)
         #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
         path: PathBuf,
+
+        /// Also look for outdated dependencies with cargo-outdated (slow, needs network)
+        #[arg(long)]
+        check_outdated: bool,
+
+        /// Run fmt, clippy, and test at the same time and report every failure
+        #[arg(long)]
+        parallel: bool,
+
+        /// Output format; json prints the project health report after the checks
+        #[arg(long, value_enum, default_value_t = RunOutputFormat::Terminal)]
+        output: RunOutputFormat,
+
+        /// Report features without updates for more than this many days as stale
+        #[arg(long, default_value_t = crate::check::DEFAULT_STALE_AFTER_DAYS)]
+        stale_days: u64,
+
+        /// Kill a check command that runs longer than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
     },
 
     /// Continuously run project checks in a loop
@@ -315,6 +748,22 @@ pub enum CheckOperation {
         /// Stop after a specific number of iterations (0 = infinite)
         #[arg(short = 'n', long, default_value = "0")]
         iterations: u64,
+
+        /// Stop at the first run with this outcome (still bounded by --iterations)
+        #[arg(long, value_enum)]
+        until: Option<crate::check::LoopUntil>,
+
+        /// Re-run when project files change instead of polling every --interval
+        #[arg(short, long)]
+        watch: bool,
+
+        /// With --watch, wait this long after the last change before re-running (in milliseconds)
+        #[arg(long, default_value = "500", value_name = "MILLIS")]
+        debounce: u64,
+
+        /// Kill a check command that runs longer than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
     },
 
     /// Verify code formatting
@@ -322,6 +771,13 @@ pub enum CheckOperation {
         /// Project path (default: current directory)
         #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
         path: PathBuf,
+
+        /// Show which files and lines would change instead of reformatting
+        #[arg(long)]
+        diff: bool,
+        /// Kill a check command that runs longer than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
     },
 
     /// Run linting checks
@@ -329,6 +785,9 @@ pub enum CheckOperation {
         /// Project path (default: current directory)
         #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
         path: PathBuf,
+        /// Kill a check command that runs longer than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
     },
 
     /// Run unit tests
@@ -336,6 +795,9 @@ pub enum CheckOperation {
         /// Project path (default: current directory)
         #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
         path: PathBuf,
+        /// Kill a check command that runs longer than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
     },
 
     /// Check Git repository status
@@ -348,8 +810,12 @@ pub enum CheckOperation {
         verbosity: String,
 
         /// Output format for the progress summary
-        #[arg(long, value_enum, default_value_t = HowtoOutputFormat::Terminal)]
-        output: HowtoOutputFormat,
+        #[arg(long, value_enum, default_value_t = ProgressOutputFormat::Terminal)]
+        output: ProgressOutputFormat,
+
+        /// Report features without updates for more than this many days as stale
+        #[arg(long, default_value_t = crate::check::DEFAULT_STALE_AFTER_DAYS)]
+        stale_days: u64,
 
         /// Project path (default: current directory)
         #[arg(short, long, default_value = ".", value_name = "PROJECT_PATH")]
@@ -362,4 +828,83 @@ pub enum HowtoOutputFormat {
     Terminal,
     Markdown,
     Html,
+    /// Machine-readable JSON, for scripts and agents
+    Json,
+}
+
+/// Output formats for the project progress summary
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum ProgressOutputFormat {
+    Terminal,
+    Markdown,
+    Html,
+    /// Prometheus text exposition format, for textfile collectors
+    Prometheus,
+    /// The full project health report as JSON
+    Json,
+}
+
+/// Output formats for `check run` and init dry runs
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum RunOutputFormat {
+    Terminal,
+    Json,
+}
+
+/// Document formats for feature export
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum ExportFormat {
+    Markdown,
+    /// Every feature, for `fargin feature import`
+    Json,
+}
+
+/// Diagram formats for the feature graph
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum GraphFormat {
+    Mermaid,
+}
+
+/// Output formats for aggregated counts
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum CountOutputFormat {
+    Terminal,
+    Json,
+}
+
+/// Write the completion script for `shell` to `out`
+pub fn write_completions(shell: clap_complete::Shell, out: &mut dyn Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_flags_resolve_log_level() {
+        let level = |args: &[&str]| Cli::try_parse_from(args).unwrap().log_level();
+
+        assert_eq!(level(&["fargin", "-vv", "undo"]), log::LevelFilter::Trace);
+        assert_eq!(level(&["fargin", "-v", "undo"]), log::LevelFilter::Debug);
+        assert_eq!(level(&["fargin", "undo"]), log::LevelFilter::Info);
+        assert_eq!(
+            level(&["fargin", "--quiet", "undo"]),
+            log::LevelFilter::Error
+        );
+        assert!(Cli::try_parse_from(["fargin", "-q", "-v", "undo"]).is_err());
+    }
+
+    #[test]
+    fn test_bash_completions_include_subcommands() {
+        let mut script = Vec::new();
+        write_completions(clap_complete::Shell::Bash, &mut script);
+        let script = String::from_utf8(script).unwrap();
+
+        assert!(script.contains("feature"));
+        assert!(script.contains("check"));
+        assert!(script.contains("fargin"));
+    }
 }
@@ -1,31 +1,124 @@
+use crate::error::FarginError;
+use crate::outln;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use toml;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Environment variable that overrides the metadata directory name
+pub const FARGIN_DIR_ENV: &str = "FARGIN_DIR";
+
+/// Metadata directory for a project, `.fargin` unless `FARGIN_DIR` is set
+///
+/// A relative `FARGIN_DIR` is resolved against the project path; an absolute
+/// one is used as-is, so several checkouts can share one location.
+pub fn fargin_dir(project_path: &Path) -> PathBuf {
+    match std::env::var_os(FARGIN_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        Some(dir) => project_path.join(dir),
+        None => project_path.join(".fargin"),
+    }
+}
+
+/// Subdirectories of the metadata directory that every Fargin project has
+///
+/// `init` creates these and `check` reports any that are missing.
+pub const FARGIN_SUBDIRS: [&str; 6] = [
+    "features",
+    "prompts",
+    "templates",
+    "history",
+    "artifacts",
+    "docs",
+];
+
+/// Version of the `config.toml` layout written by this build
+pub const CONFIG_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrades a raw config table by one schema version
+type ConfigMigration = fn(&mut toml::Table);
+
+/// Registered migrations; entry `n` upgrades a version `n` config to `n + 1`
+const CONFIG_MIGRATIONS: &[ConfigMigration] = &[migrate_v0_to_v1];
+
+/// Version 0 configs predate goals and progress markers
+fn migrate_v0_to_v1(table: &mut toml::Table) {
+    for key in ["goals", "progress_markers"] {
+        table
+            .entry(key)
+            .or_insert_with(|| toml::Value::Array(Vec::new()));
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct ProjectConfig {
+    /// Layout version; configs without one are version 0
+    #[serde(default)]
+    pub schema_version: u32,
     pub name: String,
     pub description: String,
     pub created_at: DateTime<Utc>,
     pub last_updated: DateTime<Utc>,
+    #[serde(default)]
+    pub goals: Vec<String>,
+    #[serde(default)]
+    pub progress_markers: Vec<ProgressMarker>,
+    /// Cargo binary used for checks, e.g. `cross` or a shimmed cargo
+    #[serde(default = "default_cargo_bin")]
+    pub cargo_bin: String,
+}
+
+fn default_cargo_bin() -> String {
+    "cargo".to_string()
+}
+
+/// A named milestone tracked in the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgressMarker {
+    pub name: String,
+    pub description: String,
+    pub completed: bool,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl ProgressMarker {
+    /// Create a marker that has not been completed yet
+    pub fn new(name: String, description: String) -> Self {
+        Self {
+            name,
+            description,
+            completed: false,
+            completed_at: None,
+        }
+    }
+
+    /// Mark the milestone as reached now
+    pub fn complete(&mut self) {
+        self.completed = true;
+        self.completed_at = Some(Utc::now());
+    }
 }
 
 impl ProjectConfig {
     pub fn new(name: String, description: String) -> Self {
         Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
             name,
             description,
             created_at: Utc::now(),
             last_updated: Utc::now(),
+            goals: Vec::new(),
+            progress_markers: Vec::new(),
+            cargo_bin: default_cargo_bin(),
         }
     }
 
-    pub fn save(&self, path: &Path) -> Result<()> {
-        let config_dir = path.join(".fargin");
+    pub fn save(&self, path: &Path) -> Result<(), FarginError> {
+        let config_dir = fargin_dir(path);
         fs::create_dir_all(&config_dir)?;
 
         let config_path = config_dir.join("config.toml");
@@ -40,91 +133,457 @@ impl ProjectConfig {
             fs::set_permissions(&config_dir, perms)?;
         }
 
-        fs::write(&config_path, config_str)?;
+        // Windows refuses to replace a read-only file, which editors and
+        // some checkout tools leave behind
+        #[cfg(windows)]
+        if let Ok(metadata) = fs::metadata(&config_path) {
+            let mut perms = metadata.permissions();
+            if perms.readonly() {
+                #[allow(clippy::permissions_set_readonly_false)]
+                perms.set_readonly(false);
+                fs::set_permissions(&config_path, perms).map_err(|e| {
+                    io_error_at(
+                        e,
+                        format!(
+                            "Failed to clear read-only flag on {}",
+                            config_path.display()
+                        ),
+                    )
+                })?;
+            }
+        }
+
+        // Write to a sibling temp file and rename it into place, so a crash
+        // mid-write never leaves a truncated config behind
+        let temp_path = config_dir.join("config.toml.tmp");
+        {
+            let mut file = fs::File::create(&temp_path)
+                .map_err(|e| io_error_at(e, format!("Failed to create {}", temp_path.display())))?;
+            file.write_all(config_str.as_bytes())?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, &config_path).map_err(|e| {
+            io_error_at(
+                e,
+                format!(
+                    "Failed to move {} into place at {}",
+                    temp_path.display(),
+                    config_path.display()
+                ),
+            )
+        })?;
+
+        outln!("Project configuration saved to: {}", config_path.display());
+        Ok(())
+    }
+
+    /// Keys accepted by `get_field` and `set_field`
+    pub const EDITABLE_KEYS: &'static [&'static str] =
+        &["name", "description", "goals", "cargo_bin"];
+
+    /// Read a config field as text; list fields are comma-separated
+    pub fn get_field(&self, key: &str) -> Result<String, FarginError> {
+        match key {
+            "name" => Ok(self.name.clone()),
+            "description" => Ok(self.description.clone()),
+            "goals" => Ok(self.goals.join(",")),
+            "cargo_bin" => Ok(self.cargo_bin.clone()),
+            _ => Err(Self::unknown_key(key)),
+        }
+    }
+
+    /// Set a config field from text and bump `last_updated`
+    ///
+    /// List fields take a comma-separated value; an empty value clears them.
+    pub fn set_field(&mut self, key: &str, value: &str) -> Result<(), FarginError> {
+        match key {
+            "name" => self.name = value.to_string(),
+            "description" => self.description = value.to_string(),
+            "goals" => {
+                self.goals = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|goal| !goal.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            }
+            "cargo_bin" if value.trim().is_empty() => self.cargo_bin = default_cargo_bin(),
+            "cargo_bin" => self.cargo_bin = value.trim().to_string(),
+            _ => return Err(Self::unknown_key(key)),
+        }
+
+        self.last_updated = Utc::now();
+        Ok(())
+    }
+
+    fn unknown_key(key: &str) -> FarginError {
+        FarginError::validation(format!(
+            "Unknown config key '{}'. Valid keys: {}",
+            key,
+            Self::EDITABLE_KEYS.join(", ")
+        ))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, FarginError> {
+        let config_path = fargin_dir(path).join("config.toml");
+        let config_str = match fs::read_to_string(&config_path) {
+            Ok(config_str) => config_str,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(FarginError::ConfigNotFound(config_path));
+            }
+            Err(e) => {
+                return Err(io_error_at(
+                    e,
+                    format!("Failed to read config file at {}", config_path.display()),
+                )
+                .into());
+            }
+        };
+
+        let parse_error = |message: String| FarginError::ConfigParse {
+            path: config_path.clone(),
+            message,
+        };
+        let mut table: toml::Table =
+            toml::from_str(&config_str).map_err(|e| parse_error(e.to_string()))?;
+        Self::migrate(&mut table).map_err(parse_error)?;
+
+        table.try_into().map_err(|e| parse_error(e.to_string()))
+    }
+
+    /// Bring a raw config table up to `CONFIG_SCHEMA_VERSION`
+    ///
+    /// The upgraded layout is only persisted on the next `save`.
+    fn migrate(table: &mut toml::Table) -> Result<(), String> {
+        let version = match table.get("schema_version") {
+            Some(value) => value
+                .as_integer()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or("Config schema_version must be a non-negative integer")?,
+            None => 0,
+        };
+
+        if version > CONFIG_SCHEMA_VERSION {
+            return Err(format!(
+                "Config was written by a newer fargin (schema version {}, this build supports up to {}); please upgrade fargin",
+                version,
+                CONFIG_SCHEMA_VERSION
+            ));
+        }
+
+        for migration in &CONFIG_MIGRATIONS[version as usize..] {
+            migration(table);
+        }
+        table.insert(
+            "schema_version".to_string(),
+            toml::Value::Integer(CONFIG_SCHEMA_VERSION.into()),
+        );
+
+        Ok(())
+    }
+}
+
+/// Keep the error kind while naming the file involved
+fn io_error_at(error: std::io::Error, what: String) -> std::io::Error {
+    std::io::Error::new(error.kind(), format!("{}: {}", what, error))
+}
+
+/// CI providers that Fargin can scaffold a check pipeline for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CiProvider {
+    Github,
+    Gitlab,
+}
+
+impl CiProvider {
+    /// Location of the pipeline file relative to the project root
+    pub fn config_path(&self) -> &'static str {
+        match self {
+            CiProvider::Github => ".github/workflows/fargin.yml",
+            CiProvider::Gitlab => ".gitlab-ci.yml",
+        }
+    }
+
+    /// Pipeline definition that installs the toolchain and runs the Fargin checks
+    fn pipeline(&self) -> &'static str {
+        match self {
+            CiProvider::Github => {
+                r#"name: Fargin Checks
+
+on:
+  push:
+  pull_request:
+
+jobs:
+  check:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: dtolnay/rust-toolchain@stable
+        with:
+          components: rustfmt, clippy
+      - name: Install fargin
+        run: cargo install fargin
+      - name: Run fargin checks
+        run: fargin check run
+"#
+            }
+            CiProvider::Gitlab => {
+                r#"stages:
+  - check
+
+fargin-check:
+  stage: check
+  image: rust:latest
+  before_script:
+    - rustup component add rustfmt clippy
+    - cargo install fargin
+  script:
+    - fargin check run
+"#
+            }
+        }
+    }
+}
+
+/// What an init command does, or would do under `--dry-run`
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct DryRunPlan {
+    /// Directories that are created
+    pub directories: Vec<PathBuf>,
+
+    /// Files that are written
+    pub files: Vec<PathBuf>,
+
+    /// External commands that are run
+    pub commands: Vec<String>,
+}
+
+impl DryRunPlan {
+    /// Plain-text listing for the terminal
+    pub fn summary(&self) -> String {
+        let paths = |paths: &[PathBuf]| -> Vec<String> {
+            paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect()
+        };
+
+        let mut summary = String::from("Dry run: nothing was changed.\n");
+        for (heading, items) in [
+            ("Directories to create", paths(&self.directories)),
+            ("Files to write", paths(&self.files)),
+            ("Commands to run", self.commands.clone()),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            summary.push_str(&format!("{}:\n", heading));
+            for item in items {
+                summary.push_str(&format!("  - {}\n", item));
+            }
+        }
+        summary
+    }
+}
+
+/// Carries out init steps, or only records them in a plan when `dry_run` is set
+struct Scaffold {
+    dry_run: bool,
+    plan: DryRunPlan,
+}
+
+impl Scaffold {
+    fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            plan: DryRunPlan::default(),
+        }
+    }
+
+    /// Progress messages are only printed when changes are actually made
+    fn say(&self, message: impl std::fmt::Display) {
+        if !self.dry_run {
+            outln!("{}", message);
+        }
+    }
 
-        println!("Project configuration saved to: {}", config_path.display());
+    fn create_dir_all(&mut self, path: &Path) -> Result<()> {
+        if !path.exists() && !self.plan.directories.iter().any(|dir| dir == path) {
+            self.plan.directories.push(path.to_path_buf());
+        }
+        if !self.dry_run {
+            fs::create_dir_all(path)?;
+        }
         Ok(())
     }
 
-    pub fn load(path: &Path) -> Result<Self> {
-        let config_path = path.join(".fargin").join("config.toml");
-        let config_str = fs::read_to_string(&config_path)
-            .with_context(|| format!("Failed to read config file at {:?}", config_path))?;
-        let config = toml::from_str(&config_str)?;
-        Ok(config)
+    fn write(&mut self, path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+        self.plan.files.push(path.to_path_buf());
+        if !self.dry_run {
+            fs::write(path, contents)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn save_config(&mut self, config: &ProjectConfig, project_path: &Path) -> Result<()> {
+        self.plan
+            .files
+            .push(fargin_dir(project_path).join("config.toml"));
+        if !self.dry_run {
+            config.save(project_path)?;
+        }
+        Ok(())
+    }
+
+    /// Run `cmd`, failing with `failure` if it exits unsuccessfully
+    fn run(&mut self, cmd: &mut Command, failure: &str) -> Result<()> {
+        self.plan.commands.push(command_line(cmd));
+        if self.dry_run {
+            return Ok(());
+        }
+
+        let status = cmd
+            .status()
+            .with_context(|| format!("Failed to execute {:?}", cmd.get_program()))?;
+        if !status.success() {
+            return Err(anyhow::anyhow!("{}", failure));
+        }
+        Ok(())
     }
 }
 
+/// Render a command the way it would be typed in a shell
+fn command_line(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|part| {
+            let part = part.to_string_lossy();
+            if part.is_empty() || part.contains(char::is_whitespace) {
+                format!("{:?}", part)
+            } else {
+                part.into_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Check that `name` is a valid Cargo package name
+///
+/// Names become directory names, so path separators and `..` are rejected
+/// before anything is created.
+pub fn validate_project_name(name: &str) -> Result<(), FarginError> {
+    if name.is_empty() {
+        return Err(FarginError::validation("Project name cannot be empty"));
+    }
+    if name.contains(['/', '\\']) || name.contains("..") {
+        return Err(FarginError::validation(format!(
+            "Invalid project name '{}': it must not contain path separators or '..'",
+            name
+        )));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+    {
+        return Err(FarginError::validation(format!(
+            "Invalid project name '{}': '{}' is not allowed, use letters, digits, '-' or '_'",
+            name, c
+        )));
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(FarginError::validation(format!(
+            "Invalid project name '{}': it cannot start with a digit",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse to initialize over an existing project unless `force` is set
+fn ensure_no_existing_project(project_path: &Path, force: bool) -> Result<()> {
+    if force {
+        return Ok(());
+    }
+
+    for existing in [
+        project_path.join("Cargo.toml"),
+        fargin_dir(project_path).join("config.toml"),
+    ] {
+        if existing.exists() {
+            return Err(anyhow::anyhow!(
+                "Refusing to initialize over existing project file {} (use --force to overwrite)",
+                existing.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Initialize a new Rust project using Cargo
+#[allow(clippy::too_many_arguments)]
 pub fn init_rust_project(
     name: String,
     path: PathBuf,
     cargo_bin: String,
     template: Option<String>,
     with_fargin: bool,
+    with_ci: Option<CiProvider>,
+    force: bool,
     dry_run: bool,
-) -> Result<()> {
+) -> Result<DryRunPlan> {
+    validate_project_name(&name)?;
+
     // Ensure path is relative to project root
     let project_root = std::env::current_dir()?;
     let absolute_path = project_root.join(path);
+    ensure_no_existing_project(&absolute_path.join(&name), force)?;
+    let mut scaffold = Scaffold::new(dry_run);
 
-    println!(
+    scaffold.say(format!(
         "Initializing Rust project: {} in project path: {}",
         name,
         absolute_path.display()
-    );
+    ));
 
     // Ensure project path exists
-    if !dry_run {
-        fs::create_dir_all(&absolute_path)?;
-    }
+    scaffold.create_dir_all(&absolute_path)?;
 
     // Construct project path with project name
     let project_path = absolute_path.join(&name);
 
-    // Construct Cargo command
-    let mut cargo_cmd = Command::new(cargo_bin);
-    cargo_cmd.arg("new").arg(&name).current_dir(&absolute_path);
+    // Construct Cargo command; `cargo new` has no template support, so
+    // templates go through cargo-generate like `init template`
+    let mut cargo_cmd = Command::new(&cargo_bin);
+    match &template {
+        Some(tmpl) => cargo_cmd.args(["generate", "--name", &name, "--git", tmpl]),
+        None => cargo_cmd.arg("new").arg(&name),
+    };
+    cargo_cmd.current_dir(&absolute_path);
 
-    // Add template if specified
-    if let Some(tmpl) = template {
-        cargo_cmd.arg("--template").arg(tmpl);
-    }
-
-    // Execute Cargo command
-    if !dry_run {
-        println!("Executing Cargo command: {:?}", cargo_cmd);
-        let status = cargo_cmd
-            .status()
-            .context("Failed to execute Cargo command")?;
-
-        if !status.success() {
-            return Err(anyhow::anyhow!("Cargo project initialization failed"));
-        }
-
-        println!(
-            "Project created successfully at: {}",
-            project_path.display()
-        );
-    } else {
-        println!("Dry run: Would execute command: {:?}", cargo_cmd);
-    }
+    scaffold.say(format!("Executing Cargo command: {:?}", cargo_cmd));
+    scaffold.run(&mut cargo_cmd, "Cargo project initialization failed")?;
+    scaffold.say(format!(
+        "Project created successfully at: {}",
+        project_path.display()
+    ));
 
     // Create Fargin management structure if requested
-    if with_fargin && !dry_run {
-        println!(
+    if with_fargin {
+        scaffold.say(format!(
             "Creating Fargin management structure in: {}",
             project_path.display()
-        );
-        create_fargin_structure(&project_path)?;
-    } else if dry_run && with_fargin {
-        println!(
-            "Dry run: Would create Fargin management structure in: {:?}",
-            project_path
-        );
+        ));
+        create_fargin_structure(&mut scaffold, &project_path, &cargo_bin)?;
     }
 
-    Ok(())
+    if let Some(provider) = with_ci {
+        write_ci_config(&mut scaffold, &project_path, provider, force)?;
+    }
+
+    Ok(scaffold.plan)
 }
 
 /// Initialize a project from a template
@@ -133,68 +592,59 @@ pub fn init_template_project(
     name: String,
     path: PathBuf,
     with_fargin: bool,
+    with_ci: Option<CiProvider>,
+    force: bool,
     dry_run: bool,
-) -> Result<()> {
+) -> Result<DryRunPlan> {
+    validate_project_name(&name)?;
+
     // Ensure path is relative to project root
     let project_root = std::env::current_dir()?;
     let absolute_path = project_root.join(path);
+    ensure_no_existing_project(&absolute_path.join(&name), force)?;
+    let mut scaffold = Scaffold::new(dry_run);
 
-    println!(
+    scaffold.say(format!(
         "Initializing Template project: {} from template {} in project path: {}",
         name,
         template,
         absolute_path.display()
-    );
+    ));
 
     // Ensure project path exists
-    if !dry_run {
-        fs::create_dir_all(&absolute_path)?;
-    }
+    scaffold.create_dir_all(&absolute_path)?;
 
     // Construct project path with project name
     let project_path = absolute_path.join(&name);
 
     // Example: Use cargo-generate for Rust templates
-    if !dry_run {
-        println!(
-            "Executing cargo generate command for template: {}",
-            template
-        );
-        let status = Command::new("cargo")
-            .args(["generate", "--name", &name, "--git", &template])
-            .current_dir(&absolute_path)
-            .status()
-            .context("Failed to generate project from template")?;
+    scaffold.say(format!(
+        "Executing cargo generate command for template: {}",
+        template
+    ));
+    let mut generate_cmd = Command::new("cargo");
+    generate_cmd
+        .args(["generate", "--name", &name, "--git", &template])
+        .current_dir(&absolute_path);
+    scaffold.run(&mut generate_cmd, "Template project initialization failed")?;
+    scaffold.say(format!(
+        "Template project created successfully at: {}",
+        project_path.display()
+    ));
 
-        if !status.success() {
-            return Err(anyhow::anyhow!("Template project initialization failed"));
-        }
-
-        println!(
-            "Template project created successfully at: {}",
+    if with_fargin {
+        scaffold.say(format!(
+            "Creating Fargin management structure in: {}",
             project_path.display()
-        );
-    } else {
-        println!(
-            "Dry run: Would generate project from template: {} with name: {}",
-            template, name
-        );
+        ));
+        create_fargin_structure(&mut scaffold, &project_path, "cargo")?;
     }
 
-    if with_fargin && !dry_run {
-        println!(
-            "Creating Fargin management structure in: {}",
-            project_path.display()
-        );
-        create_fargin_structure(&project_path)?;
-    } else if dry_run && with_fargin {
-        println!(
-            "Dry run: Would create Fargin management structure in: {:?}",
-            project_path
-        );
+    if let Some(provider) = with_ci {
+        write_ci_config(&mut scaffold, &project_path, provider, force)?;
     }
 
-    Ok(())
+    Ok(scaffold.plan)
 }
 
 /// Create a minimal project structure
@@ -203,34 +653,32 @@ pub fn init_minimal_project(
     path: PathBuf,
     project_type: String,
     with_fargin: bool,
+    with_ci: Option<CiProvider>,
+    force: bool,
     dry_run: bool,
-) -> Result<()> {
+) -> Result<DryRunPlan> {
+    validate_project_name(&name)?;
+
     // Ensure path is relative to project root
     let project_root = std::env::current_dir()?;
     let absolute_path = project_root.join(path);
+    ensure_no_existing_project(&absolute_path.join(&name), force)?;
+    let mut scaffold = Scaffold::new(dry_run);
 
-    println!(
+    scaffold.say(format!(
         "Initializing Minimal {} project: {} in project path: {}",
         project_type,
         name,
         absolute_path.display()
-    );
-
-    // Construct project path with project name
-    let project_path = absolute_path.join(&name);
+    ));
 
-    if !dry_run {
-        fs::create_dir_all(&project_path)?;
-    }
-
-    // Create basic project structure based on type
-    match project_type.as_str() {
-        "rust" => {
-            if !dry_run {
-                // Create minimal Rust project structure
-                println!("Creating minimal Rust project structure");
-                fs::write(
-                    project_path.join("Cargo.toml"),
+    // Starter files for each project type, relative to the project directory
+    let (label, files): (&str, Vec<(&str, String)>) = match project_type.as_str() {
+        "rust" => (
+            "Rust",
+            vec![
+                (
+                    "Cargo.toml",
                     format!(
                         r#"
 [package]
@@ -242,28 +690,18 @@ edition = "2021"
 "#,
                         name
                     ),
-                )?;
-
-                fs::create_dir_all(project_path.join("src"))?;
-                fs::write(
-                    project_path.join("src/main.rs"),
-                    "fn main() {\n    println!(\"Hello, world!\");\n}\n",
-                )?;
-
-                println!(
-                    "Minimal Rust project created successfully at: {}",
-                    project_path.display()
-                );
-            } else {
-                println!("Dry run: Would create Rust project structure for: {}", name);
-            }
-        }
-        "python" => {
-            if !dry_run {
-                // Create minimal Python project structure
-                println!("Creating minimal Python project structure");
-                fs::write(
-                    project_path.join("pyproject.toml"),
+                ),
+                (
+                    "src/main.rs",
+                    "fn main() {\n    println!(\"Hello, world!\");\n}\n".to_string(),
+                ),
+            ],
+        ),
+        "python" => (
+            "Python",
+            vec![
+                (
+                    "pyproject.toml",
                     format!(
                         r#"
 [tool.poetry]
@@ -281,62 +719,192 @@ build-backend = "poetry.core.masonry.api"
 "#,
                         name
                     ),
-                )?;
+                ),
+                ("src/__init__.py", String::new()),
+                (
+                    "src/main.py",
+                    "def main():\n    print('Hello, world!')\n\nif __name__ == '__main__':\n    main()\n"
+                        .to_string(),
+                ),
+            ],
+        ),
+        "js" | "javascript" => (
+            "JavaScript",
+            vec![
+                (
+                    "package.json",
+                    format!(
+                        r#"{{
+  "name": "{}",
+  "version": "0.1.0",
+  "private": true,
+  "main": "src/index.js",
+  "scripts": {{
+    "start": "node src/index.js"
+  }}
+}}
+"#,
+                        name
+                    ),
+                ),
+                (
+                    "src/index.js",
+                    "console.log('Hello, world!');\n".to_string(),
+                ),
+            ],
+        ),
+        "ts" | "typescript" => (
+            "TypeScript",
+            vec![
+                (
+                    "package.json",
+                    format!(
+                        r#"{{
+  "name": "{}",
+  "version": "0.1.0",
+  "private": true,
+  "main": "dist/index.js",
+  "scripts": {{
+    "build": "tsc",
+    "start": "node dist/index.js"
+  }},
+  "devDependencies": {{
+    "typescript": "^5.0.0"
+  }}
+}}
+"#,
+                        name
+                    ),
+                ),
+                (
+                    "tsconfig.json",
+                    r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "module": "commonjs",
+    "strict": true,
+    "outDir": "dist",
+    "rootDir": "src"
+  },
+  "include": ["src"]
+}
+"#
+                    .to_string(),
+                ),
+                (
+                    "src/index.ts",
+                    "console.log('Hello, world!');\n".to_string(),
+                ),
+            ],
+        ),
+        _ => return Err(anyhow::anyhow!("Unsupported project type")),
+    };
 
-                fs::create_dir_all(project_path.join("src"))?;
-                fs::write(project_path.join("src/__init__.py"), "")?;
-                fs::write(project_path.join("src/main.py"), "def main():\n    print('Hello, world!')\n\nif __name__ == '__main__':\n    main()\n")?;
+    // Construct project path with project name
+    let project_path = absolute_path.join(&name);
+    scaffold.create_dir_all(&project_path)?;
 
-                println!(
-                    "Minimal Python project created successfully at: {}",
-                    project_path.display()
-                );
-            } else {
-                println!(
-                    "Dry run: Would create Python project structure for: {}",
-                    name
-                );
-            }
+    scaffold.say(format!("Creating minimal {} project structure", label));
+    for (file, contents) in files {
+        let file_path = project_path.join(file);
+        if let Some(parent) = file_path.parent() {
+            scaffold.create_dir_all(parent)?;
         }
-        _ => return Err(anyhow::anyhow!("Unsupported project type")),
+        scaffold.write(&file_path, contents)?;
     }
+    scaffold.say(format!(
+        "Minimal {} project created successfully at: {}",
+        label,
+        project_path.display()
+    ));
 
     // Create Fargin management structure
-    if with_fargin && !dry_run {
-        println!(
+    if with_fargin {
+        scaffold.say(format!(
             "Creating Fargin management structure in: {}",
             project_path.display()
-        );
-        create_fargin_structure(&project_path)?;
-    } else if dry_run && with_fargin {
-        println!(
-            "Dry run: Would create Fargin management structure in: {:?}",
-            project_path
-        );
+        ));
+        create_fargin_structure(&mut scaffold, &project_path, "cargo")?;
+    }
+
+    if let Some(provider) = with_ci {
+        write_ci_config(&mut scaffold, &project_path, provider, force)?;
+    }
+
+    Ok(scaffold.plan)
+}
+
+/// Scaffold a CI pipeline that runs `fargin check run`
+///
+/// An existing pipeline file is left untouched unless `force` is set.
+pub fn create_ci_config(
+    project_path: &Path,
+    provider: CiProvider,
+    force: bool,
+    dry_run: bool,
+) -> Result<DryRunPlan> {
+    let mut scaffold = Scaffold::new(dry_run);
+    write_ci_config(&mut scaffold, project_path, provider, force)?;
+    Ok(scaffold.plan)
+}
+
+fn write_ci_config(
+    scaffold: &mut Scaffold,
+    project_path: &Path,
+    provider: CiProvider,
+    force: bool,
+) -> Result<()> {
+    let ci_path = project_path.join(provider.config_path());
+
+    if ci_path.exists() && !force {
+        scaffold.say(format!(
+            "CI configuration already exists at: {} (use --force to overwrite)",
+            ci_path.display()
+        ));
+        return Ok(());
     }
 
+    if let Some(parent) = ci_path.parent() {
+        scaffold.create_dir_all(parent)?;
+    }
+    scaffold.write(&ci_path, provider.pipeline())?;
+
+    scaffold.say(format!(
+        "CI configuration written to: {}",
+        ci_path.display()
+    ));
     Ok(())
 }
 
 /// Create Fargin management structure
-fn create_fargin_structure(project_path: &Path) -> Result<()> {
+fn create_fargin_structure(
+    scaffold: &mut Scaffold,
+    project_path: &Path,
+    cargo_bin: &str,
+) -> Result<()> {
     // Create .fargin directory
-    let fargin_dir = project_path.join(".fargin");
-    fs::create_dir_all(&fargin_dir)?;
+    let fargin_dir = fargin_dir(project_path);
+    scaffold.create_dir_all(&fargin_dir)?;
 
-    // Ensure project path is absolute
-    let absolute_project_path = fs::canonicalize(project_path)?;
+    // Ensure project path is absolute; in a dry run it may not exist yet
+    let absolute_project_path =
+        fs::canonicalize(project_path).unwrap_or_else(|_| project_path.to_path_buf());
 
     // Create subdirectories with more descriptive purposes
-    let subdirs = ["prompts", "templates", "history", "artifacts", "docs"];
+    let subdirs = FARGIN_SUBDIRS;
 
     for subdir in subdirs.iter() {
         let subdir_path = fargin_dir.join(subdir);
-        fs::create_dir_all(&subdir_path)?;
+        scaffold.create_dir_all(&subdir_path)?;
+
+        // Every markdown file under features/ is read as a feature
+        if *subdir == "features" {
+            continue;
+        }
 
         // Create a README for each subdirectory with descriptive content
-        fs::write(
-            subdir_path.join("README.md"),
+        scaffold.write(
+            &subdir_path.join("README.md"),
             format!(
                 "# {}\n\nThis directory is used for storing {} related to the project.",
                 subdir.to_uppercase(),
@@ -359,15 +927,17 @@ fn create_fargin_structure(project_path: &Path) -> Result<()> {
         .unwrap_or("Unnamed Project")
         .to_string();
 
-    let config = ProjectConfig::new(
+    let mut config = ProjectConfig::new(
         project_name.clone(),
         "A project managed with Fargin CLI".to_string(),
     );
-    config.save(&absolute_project_path)?;
+    // Later checks build with the same cargo the project was created with
+    config.cargo_bin = cargo_bin.to_string();
+    scaffold.save_config(&config, project_path)?;
 
     // Create a comprehensive README for the .fargin directory
-    fs::write(
-        fargin_dir.join("README.md"),
+    scaffold.write(
+        &fargin_dir.join("README.md"),
         format!(
             r#"# Fargin Project Management for {}
 
@@ -398,8 +968,8 @@ Fargin helps manage project complexity, track features, and streamline developme
     )?;
 
     // Create a basic .gitignore for the .fargin directory
-    fs::write(
-        fargin_dir.join(".gitignore"),
+    scaffold.write(
+        &fargin_dir.join(".gitignore"),
         r#"# Ignore sensitive or large artifacts
 artifacts/large_files/
 history/backups/
@@ -408,14 +978,390 @@ history/backups/
     )?;
 
     // Print debug information
-    println!(
+    scaffold.say(format!(
         "Fargin management structure created in: {}",
         fargin_dir.display()
-    );
-    println!("Subdirectories:");
+    ));
+    scaffold.say("Subdirectories:");
     for subdir in subdirs.iter() {
-        println!("- {}", subdir);
+        scaffold.say(format!("- {}", subdir));
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_v0_config_is_migrated_forward() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let config_dir = temp_dir.path().join(".fargin");
+        fs::create_dir_all(&config_dir)?;
+        fs::write(
+            config_dir.join("config.toml"),
+            "name = \"legacy\"\n\
+             description = \"Written before schema versions\"\n\
+             created_at = \"2024-01-01T00:00:00Z\"\n\
+             last_updated = \"2024-01-01T00:00:00Z\"\n",
+        )?;
+
+        let config = ProjectConfig::load(temp_dir.path())?;
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.name, "legacy");
+        assert!(config.goals.is_empty());
+        assert!(config.progress_markers.is_empty());
+
+        config.save(temp_dir.path())?;
+        let saved = fs::read_to_string(config_dir.join("config.toml"))?;
+        assert!(saved.contains(&format!("schema_version = {}", CONFIG_SCHEMA_VERSION)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_newer_config_is_rejected() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut config = ProjectConfig::new("future".to_string(), "Demo".to_string());
+        config.schema_version = CONFIG_SCHEMA_VERSION + 1;
+        config.save(temp_dir.path())?;
+
+        let error = ProjectConfig::load(temp_dir.path()).unwrap_err();
+        assert!(error.to_string().contains("written by a newer fargin"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_is_atomic_and_leaves_no_temp_file() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let config = ProjectConfig::new("demo".to_string(), "Demo".to_string());
+
+        config.save(temp_dir.path())?;
+        config.save(temp_dir.path())?;
+
+        assert!(!temp_dir.path().join(".fargin/config.toml.tmp").exists());
+        assert_eq!(ProjectConfig::load(temp_dir.path())?, config);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_description_persists() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let config = ProjectConfig::new("demo".to_string(), "Old".to_string());
+        let before = config.last_updated;
+        config.save(temp_dir.path())?;
+
+        let mut config = ProjectConfig::load(temp_dir.path())?;
+        config.set_field("description", "A better description")?;
+        config.save(temp_dir.path())?;
+
+        let reloaded = ProjectConfig::load(temp_dir.path())?;
+        assert_eq!(reloaded.get_field("description")?, "A better description");
+        assert!(reloaded.last_updated >= before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_config_key_is_rejected() {
+        let mut config = ProjectConfig::new("demo".to_string(), "Demo".to_string());
+
+        let error = config.set_field("colour", "blue").unwrap_err().to_string();
+        assert!(error.contains("Unknown config key 'colour'"));
+        assert!(error.contains("name, description, goals"));
+        assert!(config.get_field("colour").is_err());
+        assert_eq!(config.description, "Demo");
+    }
+
+    #[test]
+    fn test_config_round_trips_goals_and_markers() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        let mut config = ProjectConfig::new("demo".to_string(), "Demo project".to_string());
+        config.goals.push("Ship the CLI".to_string());
+        config.goals.push("Document every command".to_string());
+        config.progress_markers.push(ProgressMarker::new(
+            "mvp".to_string(),
+            "Minimum viable product".to_string(),
+        ));
+        config.save(temp_dir.path())?;
+
+        assert_eq!(ProjectConfig::load(temp_dir.path())?, config);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_progress_marker_complete() {
+        let mut marker = ProgressMarker::new("beta".to_string(), "Public beta".to_string());
+        assert!(!marker.completed);
+        assert!(marker.completed_at.is_none());
+
+        let before = Utc::now();
+        marker.complete();
+
+        assert!(marker.completed);
+        assert!(marker.completed_at.is_some_and(|at| at >= before));
+    }
+
+    #[test]
+    fn test_create_ci_config() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        create_ci_config(temp_dir.path(), CiProvider::Github, false, false)?;
+
+        let workflow = fs::read_to_string(temp_dir.path().join(".github/workflows/fargin.yml"))?;
+        assert!(workflow.contains("fargin check run"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_ci_config_respects_existing_file() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let ci_path = temp_dir.path().join(".gitlab-ci.yml");
+        fs::write(&ci_path, "custom")?;
+
+        create_ci_config(temp_dir.path(), CiProvider::Gitlab, false, false)?;
+        assert_eq!(fs::read_to_string(&ci_path)?, "custom");
+
+        create_ci_config(temp_dir.path(), CiProvider::Gitlab, true, false)?;
+        assert!(fs::read_to_string(&ci_path)?.contains("fargin check run"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_ci_config_dry_run() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        create_ci_config(temp_dir.path(), CiProvider::Github, false, true)?;
+        assert!(!temp_dir.path().join(".github").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_minimal_dry_run_returns_plan_without_writing() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let root = temp_dir.path().to_path_buf();
+
+        let plan = init_minimal_project(
+            "demo".to_string(),
+            root.clone(),
+            "rust".to_string(),
+            true,
+            None,
+            false,
+            true,
+        )?;
+
+        let project = root.join("demo");
+        assert!(plan.directories.contains(&project));
+        assert!(plan.files.contains(&project.join("Cargo.toml")));
+        assert!(plan.files.contains(&project.join("src/main.rs")));
+        assert!(plan.files.contains(&project.join(".fargin/config.toml")));
+        assert!(plan.commands.is_empty());
+        assert!(!project.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_refuses_existing_project() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let project = temp_dir.path().join("demo");
+        fs::create_dir_all(&project)?;
+        fs::write(project.join("Cargo.toml"), "[package]\nname = \"keep\"\n")?;
+
+        let err = init_minimal_project(
+            "demo".to_string(),
+            temp_dir.path().to_path_buf(),
+            "rust".to_string(),
+            false,
+            None,
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Cargo.toml"), "{}", err);
+        assert!(err.to_string().contains("--force"), "{}", err);
+        assert_eq!(
+            fs::read_to_string(project.join("Cargo.toml"))?,
+            "[package]\nname = \"keep\"\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_refuses_existing_fargin_config() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let project = temp_dir.path().join("demo");
+        ProjectConfig::new("demo".to_string(), "existing".to_string()).save(&project)?;
+
+        let err = init_minimal_project(
+            "demo".to_string(),
+            temp_dir.path().to_path_buf(),
+            "python".to_string(),
+            true,
+            None,
+            false,
+            true,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("config.toml"), "{}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_force_overwrites_existing_project() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let project = temp_dir.path().join("demo");
+        fs::create_dir_all(&project)?;
+        fs::write(project.join("Cargo.toml"), "[package]\nname = \"keep\"\n")?;
+
+        init_minimal_project(
+            "demo".to_string(),
+            temp_dir.path().to_path_buf(),
+            "rust".to_string(),
+            false,
+            None,
+            true,
+            false,
+        )?;
+        assert!(fs::read_to_string(project.join("Cargo.toml"))?.contains("name = \"demo\""));
+        assert!(project.join("src/main.rs").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_project_name() {
+        assert!(validate_project_name("my-project_2").is_ok());
+
+        let err = validate_project_name("my project").unwrap_err();
+        assert!(err.to_string().contains("' '"), "{}", err);
+
+        let err = validate_project_name("../escape").unwrap_err();
+        assert!(err.to_string().contains("path separators"), "{}", err);
+
+        assert!(validate_project_name("2fast").is_err());
+        assert!(validate_project_name("").is_err());
+    }
+
+    #[test]
+    fn test_init_rejects_invalid_name_before_creating_anything() {
+        let temp_dir = tempdir().unwrap();
+
+        let result = init_minimal_project(
+            "../escape".to_string(),
+            temp_dir.path().join("nested"),
+            "rust".to_string(),
+            false,
+            None,
+            false,
+            false,
+        );
+        assert!(result.is_err());
+        assert!(!temp_dir.path().join("nested").exists());
+        assert!(!temp_dir.path().join("escape").exists());
+    }
+
+    #[test]
+    fn test_minimal_js_and_typescript_projects() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let root = temp_dir.path().to_path_buf();
+
+        for (name, project_type) in [("web", "js"), ("app", "typescript")] {
+            init_minimal_project(
+                name.to_string(),
+                root.clone(),
+                project_type.to_string(),
+                false,
+                None,
+                false,
+                false,
+            )?;
+        }
+
+        let package: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(root.join("web/package.json"))?)?;
+        assert_eq!(package["name"], "web");
+        assert!(root.join("web/src/index.js").exists());
+        assert!(!root.join("web/tsconfig.json").exists());
+
+        assert!(root.join("app/package.json").exists());
+        let tsconfig: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(root.join("app/tsconfig.json"))?)?;
+        assert_eq!(tsconfig["compilerOptions"]["strict"], true);
+        assert!(root.join("app/src/index.ts").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_initialized_structure_has_all_recommended_dirs() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        init_minimal_project(
+            "demo".to_string(),
+            temp_dir.path().to_path_buf(),
+            "rust".to_string(),
+            true,
+            None,
+            false,
+            false,
+        )?;
+        let project = temp_dir.path().join("demo");
+
+        for subdir in FARGIN_SUBDIRS {
+            assert!(fargin_dir(&project).join(subdir).is_dir(), "{}", subdir);
+        }
+
+        let report = crate::check::ProjectChecker::new(&project).check_file_structure()?;
+        let missing_meta: Vec<_> = report
+            .missing_dirs
+            .iter()
+            .filter(|dir| dir.starts_with(".fargin"))
+            .collect();
+        assert!(missing_meta.is_empty(), "{:?}", missing_meta);
+
+        let features = crate::features::FeatureManager::new(&project)?;
+        assert!(features.list_features(None, None, None, None).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_config_is_config_not_found() {
+        let temp_dir = tempdir().unwrap();
+
+        match ProjectConfig::load(temp_dir.path()) {
+            Err(FarginError::ConfigNotFound(path)) => {
+                assert_eq!(path, fargin_dir(temp_dir.path()).join("config.toml"))
+            }
+            other => panic!("expected ConfigNotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_invalid_config_is_config_parse() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let config_dir = fargin_dir(temp_dir.path());
+        fs::create_dir_all(&config_dir)?;
+        fs::write(config_dir.join("config.toml"), "name = [unterminated")?;
+
+        assert!(matches!(
+            ProjectConfig::load(temp_dir.path()),
+            Err(FarginError::ConfigParse { .. })
+        ));
+
+        Ok(())
+    }
+}
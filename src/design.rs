@@ -0,0 +1,492 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::fargin_dir;
+
+/// Review lifecycle of a design document
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DesignStatus {
+    Draft,
+    Reviewed,
+    Approved,
+    Implemented,
+    Archived,
+}
+
+impl DesignStatus {
+    /// Whether a design may move from this status to `next` without `--force`
+    ///
+    /// Designs advance Draft → Reviewed → Approved → Implemented, may be sent
+    /// back one step for rework, and can be archived from any status.
+    /// Archived designs are final.
+    pub fn can_transition_to(self, next: DesignStatus) -> bool {
+        use DesignStatus::*;
+
+        self == next
+            || matches!(
+                (self, next),
+                (Draft, Reviewed)
+                    | (Reviewed, Draft)
+                    | (Reviewed, Approved)
+                    | (Approved, Reviewed)
+                    | (Approved, Implemented)
+                    | (Draft | Reviewed | Approved | Implemented, Archived)
+            )
+    }
+}
+
+impl fmt::Display for DesignStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::str::FromStr for DesignStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "draft" => Ok(DesignStatus::Draft),
+            "reviewed" => Ok(DesignStatus::Reviewed),
+            "approved" => Ok(DesignStatus::Approved),
+            "implemented" => Ok(DesignStatus::Implemented),
+            "archived" => Ok(DesignStatus::Archived),
+            _ => Err(format!("Invalid design status: {}", s)),
+        }
+    }
+}
+
+/// An architectural design document
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Design {
+    /// Unique identifier, also the file stem under `.fargin/docs`
+    pub id: String,
+
+    /// Human-readable name of the design
+    pub name: String,
+
+    /// Review status of the design
+    pub status: DesignStatus,
+
+    /// Timestamp of design creation
+    pub created_at: DateTime<Utc>,
+
+    /// Design description (stored in the markdown body)
+    #[serde(skip)]
+    pub description: Option<String>,
+}
+
+/// Fields of a design that can be changed after creation
+#[derive(Debug, Default)]
+pub struct DesignUpdateRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<DesignStatus>,
+
+    /// Allow status changes that skip or reverse the normal lifecycle
+    pub force: bool,
+}
+
+/// Design document management system
+pub struct DesignManager {
+    /// Path to the project root
+    project_path: PathBuf,
+
+    /// In-memory cache of designs
+    designs: HashMap<String, Design>,
+}
+
+impl DesignManager {
+    /// Create a new design manager and load existing documents
+    pub fn new(project_path: &Path) -> Result<Self> {
+        let mut design_manager = Self {
+            project_path: project_path.to_path_buf(),
+            designs: HashMap::new(),
+        };
+
+        design_manager.load_designs()?;
+
+        Ok(design_manager)
+    }
+
+    fn docs_path(&self) -> PathBuf {
+        fargin_dir(&self.project_path).join("docs")
+    }
+
+    /// Load designs from filesystem
+    fn load_designs(&mut self) -> Result<()> {
+        self.designs.clear();
+
+        let docs_path = self.docs_path();
+        if !docs_path.exists() {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(&docs_path)? {
+            let path = entry?.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("md") {
+                continue;
+            }
+
+            // `.fargin/docs` also holds the README written by init and other notes
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if id.eq_ignore_ascii_case("README") {
+                continue;
+            }
+
+            let design = fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| {
+                    if is_design_document(&content) {
+                        Self::design_from_file(id, &content).map(Some)
+                    } else {
+                        Ok(None)
+                    }
+                });
+            match design {
+                Ok(Some(design)) => {
+                    self.designs.insert(design.id.clone(), design);
+                }
+                Ok(None) => {}
+                Err(e) => log::warn!("Skipping design {}: {:#}", path.display(), e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create a new design document and return its ID
+    pub fn create(&mut self, name: String, description: Option<String>) -> Result<String> {
+        let id = generate_design_id(&name);
+
+        if self.designs.contains_key(&id) {
+            return Err(anyhow::anyhow!("Design with this name already exists"));
+        }
+
+        let design = Design {
+            id: id.clone(),
+            name,
+            status: DesignStatus::Draft,
+            created_at: Utc::now(),
+            description,
+        };
+
+        self.save_design(&design)?;
+        self.designs.insert(id.clone(), design);
+
+        Ok(id)
+    }
+
+    /// All designs, oldest first
+    pub fn list(&self) -> Vec<&Design> {
+        let mut designs: Vec<&Design> = self.designs.values().collect();
+        // Design IDs start with a timestamp, so this keeps chronological order
+        designs.sort_by(|a, b| a.id.cmp(&b.id));
+        designs
+    }
+
+    /// Get a specific design by ID
+    pub fn get(&self, id: &str) -> Option<&Design> {
+        self.designs.get(id)
+    }
+
+    /// Update an existing design
+    pub fn update(&mut self, id: &str, updates: DesignUpdateRequest) -> Result<()> {
+        let design = self
+            .designs
+            .get_mut(id)
+            .with_context(|| format!("Design document '{}' not found", id))?;
+
+        if let Some(name) = updates.name {
+            design.name = name;
+        }
+        if let Some(description) = updates.description {
+            design.description = Some(description);
+        }
+        if let Some(status) = updates.status {
+            if !updates.force && !design.status.can_transition_to(status) {
+                return Err(anyhow::anyhow!(
+                    "Cannot move design '{}' from {} to {} (use --force to override)",
+                    id,
+                    design.status,
+                    status
+                ));
+            }
+            design.status = status;
+        }
+
+        let design_clone = design.clone();
+        self.save_design(&design_clone)
+    }
+
+    /// Delete a design
+    pub fn delete(&mut self, id: &str) -> Result<()> {
+        if self.designs.remove(id).is_none() {
+            return Err(anyhow::anyhow!("Design document '{}' not found", id));
+        }
+
+        let design_path = self.docs_path().join(format!("{}.md", id));
+        if design_path.exists() {
+            fs::remove_file(design_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Save design to filesystem
+    fn save_design(&self, design: &Design) -> Result<()> {
+        let docs_path = self.docs_path();
+        fs::create_dir_all(&docs_path)?;
+
+        let content = format!(
+            "---\n{}---\n\n# Design: {}\n\n## Description\n{}\n",
+            serde_yaml::to_string(design)?,
+            design.name,
+            design
+                .description
+                .as_deref()
+                .unwrap_or("No description provided")
+        );
+
+        fs::write(docs_path.join(format!("{}.md", design.id)), content)?;
+        Ok(())
+    }
+
+    /// Parse a design file written by `save_design`
+    fn design_from_file(id: &str, content: &str) -> Result<Design> {
+        let (mut design, body) = match content
+            .strip_prefix("---\n")
+            .and_then(|rest| rest.split_once("\n---\n"))
+        {
+            Some((yaml, body)) => (
+                serde_yaml::from_str::<Design>(yaml)
+                    .with_context(|| format!("Invalid front matter in design {}", id))?,
+                body,
+            ),
+            // Documents written before front matter only have the markdown body
+            None => (
+                Design {
+                    id: id.to_string(),
+                    name: section_lines(content, "# Design: ")
+                        .first()
+                        .cloned()
+                        .unwrap_or_else(|| id.to_string()),
+                    status: section_lines(content, "## Status")
+                        .first()
+                        .and_then(|status| status.parse().ok())
+                        .unwrap_or(DesignStatus::Draft),
+                    created_at: Utc::now(),
+                    description: None,
+                },
+                content,
+            ),
+        };
+
+        let description = section_lines(body, "## Description").join("\n");
+        if !description.is_empty() && description != "No description provided" {
+            design.description = Some(description);
+        }
+
+        Ok(design)
+    }
+}
+
+/// Whether a markdown file in `.fargin/docs` is a design rather than other documentation
+fn is_design_document(content: &str) -> bool {
+    content.starts_with("---\n") || content.lines().any(|line| line.starts_with("# Design: "))
+}
+
+/// Non-empty lines of the markdown section under `heading`
+///
+/// For a `# ` title heading, the text after the heading on the same line is returned.
+fn section_lines(content: &str, heading: &str) -> Vec<String> {
+    if !heading.starts_with("##") {
+        return content
+            .lines()
+            .find_map(|line| line.strip_prefix(heading))
+            .map(|title| vec![title.trim().to_string()])
+            .unwrap_or_default();
+    }
+
+    content
+        .lines()
+        .skip_while(|line| line.trim() != heading)
+        .skip(1)
+        .take_while(|line| !line.starts_with("## "))
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Generate a sortable, unique design ID from its name
+fn generate_design_id(name: &str) -> String {
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+    let slug = name
+        .to_lowercase()
+        .replace(char::is_whitespace, "_")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect::<String>();
+
+    format!("{}__{}", timestamp, slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_create_list_show_round_trip() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = DesignManager::new(temp_dir.path())?;
+
+        let id = manager.create(
+            "Event Bus".to_string(),
+            Some("Decouple producers from consumers".to_string()),
+        )?;
+
+        let reloaded = DesignManager::new(temp_dir.path())?;
+        let listed: Vec<&str> = reloaded.list().iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(listed, vec![id.as_str()]);
+
+        let design = reloaded.get(&id).expect("Design should resolve by its ID");
+        assert_eq!(design.name, "Event Bus");
+        assert_eq!(design.status, DesignStatus::Draft);
+        assert_eq!(
+            design.description.as_deref(),
+            Some("Decouple producers from consumers")
+        );
+        assert_eq!(design, manager.get(&id).unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_and_delete() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = DesignManager::new(temp_dir.path())?;
+
+        let id = manager.create("Cache Layer".to_string(), None)?;
+        manager.update(
+            &id,
+            DesignUpdateRequest {
+                description: Some("Read-through cache".to_string()),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            DesignManager::new(temp_dir.path())?
+                .get(&id)
+                .and_then(|d| d.description.clone())
+                .as_deref(),
+            Some("Read-through cache")
+        );
+
+        manager.delete(&id)?;
+        assert!(DesignManager::new(temp_dir.path())?.list().is_empty());
+        assert!(manager.delete(&id).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_legacy_design_without_front_matter() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let docs = temp_dir.path().join(".fargin/docs");
+        fs::create_dir_all(&docs)?;
+        fs::write(
+            docs.join("20240101_000000__old.md"),
+            "# Design: Old\n\n## Description\nLegacy doc\n\n## Status\nDraft\n",
+        )?;
+
+        let manager = DesignManager::new(temp_dir.path())?;
+        let design = manager.get("20240101_000000__old").unwrap();
+
+        assert_eq!(design.name, "Old");
+        assert_eq!(design.description.as_deref(), Some("Legacy doc"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_skips_other_docs_and_bad_files() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = DesignManager::new(temp_dir.path())?;
+        let id = manager.create("Caching".to_string(), None)?;
+
+        let docs = temp_dir.path().join(".fargin/docs");
+        fs::write(docs.join("README.md"), "# Project Documentation\n")?;
+        fs::write(docs.join("notes.md"), "# Meeting notes\n")?;
+        fs::write(docs.join("broken.md"), "---\nstatus: [\n---\n")?;
+
+        let manager = DesignManager::new(temp_dir.path())?;
+        let ids: Vec<&str> = manager.list().iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec![id.as_str()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_valid_status_transition() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = DesignManager::new(temp_dir.path())?;
+        let id = manager.create("Sharding".to_string(), None)?;
+
+        for status in [DesignStatus::Reviewed, DesignStatus::Approved] {
+            manager.update(
+                &id,
+                DesignUpdateRequest {
+                    status: Some(status),
+                    ..Default::default()
+                },
+            )?;
+        }
+
+        let reloaded = DesignManager::new(temp_dir.path())?;
+        assert_eq!(reloaded.get(&id).unwrap().status, DesignStatus::Approved);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejected_status_transition() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = DesignManager::new(temp_dir.path())?;
+        let id = manager.create("Legacy API".to_string(), None)?;
+
+        let archive = DesignUpdateRequest {
+            status: Some(DesignStatus::Archived),
+            ..Default::default()
+        };
+        manager.update(&id, archive)?;
+
+        let revive = || DesignUpdateRequest {
+            status: Some(DesignStatus::Draft),
+            ..Default::default()
+        };
+        let err = manager.update(&id, revive()).unwrap_err();
+        assert!(err.to_string().contains("from Archived to Draft"));
+        assert_eq!(manager.get(&id).unwrap().status, DesignStatus::Archived);
+
+        manager.update(
+            &id,
+            DesignUpdateRequest {
+                force: true,
+                ..revive()
+            },
+        )?;
+        assert_eq!(manager.get(&id).unwrap().status, DesignStatus::Draft);
+
+        Ok(())
+    }
+}
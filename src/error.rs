@@ -0,0 +1,53 @@
+//! Error type for the library API
+//!
+//! `ProjectConfig`, `FeatureManager` and `UndoJournal` return
+//! [`FarginError`] so callers can match on what went wrong. The CLI converts
+//! it into `anyhow::Error` at the command boundary.
+
+use std::path::PathBuf;
+
+/// Failures reported by the fargin library
+#[derive(Debug, thiserror::Error)]
+pub enum FarginError {
+    /// The project has no `config.toml`
+    #[error("No fargin config found at {}", .0.display())]
+    ConfigNotFound(PathBuf),
+
+    /// `config.toml` exists but could not be understood
+    #[error("Failed to parse config file at {}: {message}", path.display())]
+    ConfigParse { path: PathBuf, message: String },
+
+    /// No feature has this ID
+    #[error("Feature not found: {0}")]
+    FeatureNotFound(String),
+
+    /// A feature file, history file or journal entry could not be parsed
+    #[error("Failed to parse {}: {message}", path.display())]
+    Parse { path: PathBuf, message: String },
+
+    /// The request was rejected before anything was changed
+    #[error("{0}")]
+    Validation(String),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error(transparent)]
+    TomlSerialize(#[from] toml::ser::Error),
+}
+
+impl FarginError {
+    /// Shorthand for [`FarginError::Validation`]
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::Validation(message.into())
+    }
+}
+
+/// Result type used across the library API
+pub type Result<T, E = FarginError> = std::result::Result<T, E>;
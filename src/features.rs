@@ -1,12 +1,18 @@
-use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::hash::Hash;
+use std::io::{BufRead, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::SystemTime;
+
+use crate::config::fargin_dir;
+use crate::error::{FarginError, Result};
+use crate::howto::html_escape;
+use crate::undo::{UndoJournal, UndoOperation};
 
 /// Priority levels for features
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, ValueEnum)]
@@ -31,8 +37,30 @@ impl FromStr for Priority {
     }
 }
 
+impl Priority {
+    /// Next more urgent priority, clamped at `Critical`
+    pub fn raised(self) -> Self {
+        match self {
+            Priority::Critical | Priority::High => Priority::Critical,
+            Priority::Medium => Priority::High,
+            Priority::Low => Priority::Medium,
+        }
+    }
+
+    /// Next less urgent priority, clamped at `Low`
+    pub fn lowered(self) -> Self {
+        match self {
+            Priority::Critical => Priority::High,
+            Priority::High => Priority::Medium,
+            Priority::Medium | Priority::Low => Priority::Low,
+        }
+    }
+}
+
 /// Current status of a feature
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Copy, ValueEnum, Hash)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, ValueEnum, Hash,
+)]
 pub enum FeatureStatus {
     Proposed,
     InProgress,
@@ -56,6 +84,27 @@ impl FromStr for FeatureStatus {
     }
 }
 
+impl FeatureStatus {
+    /// Whether a feature may move from this status to `next` without forcing
+    ///
+    /// Work moves forward from Proposed through InProgress to Implemented and
+    /// may be blocked or shelved along the way. Implemented features can be
+    /// reopened or deprecated. Deprecated features are final.
+    pub fn can_transition_to(self, next: FeatureStatus) -> bool {
+        use FeatureStatus::*;
+
+        self == next
+            || matches!(
+                (self, next),
+                (Proposed, InProgress | Implemented | Blocked)
+                    | (InProgress, Proposed | Implemented | Blocked)
+                    | (Blocked, Proposed | InProgress)
+                    | (Implemented, InProgress)
+                    | (Proposed | InProgress | Implemented | Blocked, Deprecated)
+            )
+    }
+}
+
 /// Detailed feature representation
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Feature {
@@ -94,6 +143,291 @@ pub struct Feature {
 
     /// Acceptance criteria
     pub acceptance_criteria: Vec<String>,
+
+    /// Manual ordering within the feature's status column (lower comes first)
+    pub rank: Option<f64>,
+
+    /// When the feature should be implemented by
+    #[serde(default)]
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+/// Machine-readable fields stored as YAML front matter at the top of a feature file
+#[derive(Debug, Serialize, Deserialize)]
+struct FeatureFrontMatter {
+    id: String,
+    /// Absent in files written before the name was stored here
+    #[serde(default)]
+    name: Option<String>,
+    status: FeatureStatus,
+    priority: Priority,
+    #[serde(default)]
+    tags: Vec<String>,
+    assigned_to: Option<String>,
+    complexity: Option<u8>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    #[serde(default)]
+    related_features: Vec<String>,
+    #[serde(default)]
+    acceptance_criteria: Vec<String>,
+    #[serde(default)]
+    rank: Option<f64>,
+    #[serde(default)]
+    due_date: Option<DateTime<Utc>>,
+}
+
+/// Feature fields that can be aggregated with `count_by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CountField {
+    Status,
+    Priority,
+    Tag,
+    Assignee,
+}
+
+/// Project-wide feature rollup produced by `FeatureManager::statistics`
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct FeatureStats {
+    /// Number of features
+    pub total: usize,
+
+    /// Feature count per status
+    pub by_status: BTreeMap<FeatureStatus, usize>,
+
+    /// Feature count per priority
+    pub by_priority: BTreeMap<Priority, usize>,
+
+    /// Features with nobody assigned
+    pub unassigned: usize,
+
+    /// Mean complexity of features that have one
+    pub average_complexity: Option<f64>,
+
+    /// Unfinished features past their due date
+    pub overdue: usize,
+
+    /// IDs of features sharing a name, keyed by that name
+    pub duplicate_names: BTreeMap<String, Vec<String>>,
+}
+
+impl FeatureStats {
+    fn average_complexity_text(&self) -> String {
+        self.average_complexity
+            .map(|average| format!("{:.1}", average))
+            .unwrap_or_else(|| "n/a".to_string())
+    }
+
+    /// Plain-text summary for the terminal
+    pub fn summary(&self) -> String {
+        let mut summary = format!("📊 Feature Statistics\n   Total Features: {}\n", self.total);
+        summary.push_str("   By Status:\n");
+        for (status, count) in &self.by_status {
+            summary.push_str(&format!("     - {}: {}\n", status, count));
+        }
+        summary.push_str("   By Priority:\n");
+        for (priority, count) in &self.by_priority {
+            summary.push_str(&format!("     - {:?}: {}\n", priority, count));
+        }
+        summary.push_str(&format!("   Unassigned: {}\n", self.unassigned));
+        summary.push_str(&format!("   Overdue: {}\n", self.overdue));
+        summary.push_str(&format!(
+            "   Average Complexity: {}\n",
+            self.average_complexity_text()
+        ));
+        if !self.duplicate_names.is_empty() {
+            summary.push_str("   Duplicate Names:\n");
+            for (name, ids) in &self.duplicate_names {
+                summary.push_str(&format!("     - {}: {}\n", name, ids.join(", ")));
+            }
+        }
+        summary
+    }
+
+    /// Markdown report with one table per distribution
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# Feature Statistics\n\n");
+        markdown.push_str(&format!("- **Total Features**: {}\n", self.total));
+        markdown.push_str(&format!("- **Unassigned**: {}\n", self.unassigned));
+        markdown.push_str(&format!("- **Overdue**: {}\n", self.overdue));
+        markdown.push_str(&format!(
+            "- **Average Complexity**: {}\n\n",
+            self.average_complexity_text()
+        ));
+
+        markdown.push_str("## By Status\n\n| Status | Count |\n| --- | --- |\n");
+        for (status, count) in &self.by_status {
+            markdown.push_str(&format!("| {} | {} |\n", status, count));
+        }
+
+        markdown.push_str("\n## By Priority\n\n| Priority | Count |\n| --- | --- |\n");
+        for (priority, count) in &self.by_priority {
+            markdown.push_str(&format!("| {:?} | {} |\n", priority, count));
+        }
+
+        if !self.duplicate_names.is_empty() {
+            markdown.push_str("\n## Duplicate Names\n\n| Name | Features |\n| --- | --- |\n");
+            for (name, ids) in &self.duplicate_names {
+                markdown.push_str(&format!("| {} | {} |\n", name, ids.join(", ")));
+            }
+        }
+
+        markdown
+    }
+}
+
+/// Outcome of an interactive triage session
+#[derive(Debug, Default, PartialEq)]
+pub struct TriageSummary {
+    /// Features changed by an action
+    pub updated: usize,
+
+    /// Features explicitly skipped
+    pub skipped: usize,
+
+    /// Features not reached before quitting
+    pub remaining: usize,
+}
+
+/// Which feature wins conflicting scalar fields (status, assignee) in a merge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MergePreference {
+    /// Keep the values of the feature being kept
+    Keep,
+    /// Take the values of the feature being dropped
+    Drop,
+}
+
+/// What `merge_features` combined
+#[derive(Debug)]
+pub struct MergeSummary {
+    /// The feature after merging
+    pub kept: Feature,
+
+    /// The duplicate that was archived
+    pub dropped: Feature,
+
+    /// Tags that came from the dropped feature
+    pub added_tags: Vec<String>,
+
+    /// Acceptance criteria that came from the dropped feature
+    pub added_criteria: Vec<String>,
+
+    /// Features whose references were re-pointed to the kept feature
+    pub repointed: Vec<String>,
+
+    /// Where the dropped feature was archived
+    pub archived_path: PathBuf,
+}
+
+/// One entry in a feature's status history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusChange {
+    /// When the status changed
+    pub timestamp: DateTime<Utc>,
+
+    /// Status before the change
+    pub from: FeatureStatus,
+
+    /// Status after the change
+    pub to: FeatureStatus,
+}
+
+/// How `import_json` handles a feature whose ID already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConflictPolicy {
+    /// Keep the existing feature and drop the imported one
+    Skip,
+    /// Replace the existing feature with the imported one
+    Overwrite,
+    /// Import under a new ID, leaving the existing feature alone
+    Rename,
+}
+
+/// What `import_json` did with each imported feature
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportSummary {
+    /// IDs of features written, after any renaming
+    pub imported: Vec<String>,
+
+    /// IDs that already existed and were left alone
+    pub skipped: Vec<String>,
+
+    /// Features imported under a new ID, as (original, new) pairs
+    pub renamed: Vec<(String, String)>,
+}
+
+/// Sort orders for feature listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FeatureSort {
+    /// Oldest first (the default)
+    Created,
+    /// Most recently updated first
+    Updated,
+    /// Most urgent first
+    Priority,
+    /// Alphabetical by name, ignoring case
+    Name,
+    /// Grouped by status in workflow order
+    Status,
+    /// Manual rank within each status column, unranked features last
+    Rank,
+}
+
+/// What a feature file looked like when it was last parsed
+#[derive(Debug, Clone, PartialEq)]
+struct LoadedFile {
+    modified: SystemTime,
+    len: u64,
+    id: String,
+}
+
+/// Parse feature files on up to `threads` scoped threads
+///
+/// Results come back in the same order as `paths`.
+fn parse_feature_files(paths: &[&Path], threads: usize) -> Vec<Result<Feature>> {
+    if paths.is_empty() {
+        return Vec::new();
+    }
+
+    let chunk_size = paths.len().div_ceil(threads.max(1));
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|path| parse_feature_file(path))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("feature parser thread panicked"))
+            .collect()
+    })
+}
+
+/// Read and parse one feature file, taking the ID from the filename
+fn parse_feature_file(path: &Path) -> Result<Feature> {
+    let content = fs::read_to_string(path)?;
+
+    // Extract ID from filename
+    let id = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| FarginError::Parse {
+            path: path.to_path_buf(),
+            message: "invalid feature filename".to_string(),
+        })?;
+
+    FeatureManager::feature_from_file(id, &content).map_err(|e| FarginError::Parse {
+        path: path.to_path_buf(),
+        message: format!("invalid front matter: {}", e),
+    })
 }
 
 /// Feature management system
@@ -103,72 +437,126 @@ pub struct FeatureManager {
 
     /// In-memory cache of features
     features: HashMap<String, Feature>,
+
+    /// Feature files as of the last load, so `reload` can skip unchanged ones
+    loaded_files: HashMap<PathBuf, LoadedFile>,
+
+    /// Number of feature files parsed so far
+    parsed_files: usize,
+
+    /// How many threads parse feature files on load
+    load_threads: usize,
 }
 
 impl FeatureManager {
-    /// Create a new feature manager
+    /// Create a new feature manager, creating the features directory if needed
     pub fn new(project_path: &Path) -> Result<Self> {
+        fs::create_dir_all(fargin_dir(project_path).join("features"))?;
+        Self::open(project_path)
+    }
+
+    /// Open the features of a project without touching the filesystem
+    ///
+    /// A project without a features directory has no features. The directory
+    /// is created when the first feature is saved.
+    pub fn open(project_path: &Path) -> Result<Self> {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self::open_with_threads(project_path, threads)
+    }
+
+    /// Like [`FeatureManager::open`], parsing feature files on up to `threads` threads
+    pub fn open_with_threads(project_path: &Path, threads: usize) -> Result<Self> {
         let mut feature_manager = Self {
             project_path: project_path.to_path_buf(),
             features: HashMap::new(),
+            loaded_files: HashMap::new(),
+            parsed_files: 0,
+            load_threads: threads.max(1),
         };
 
-        feature_manager.load_features()?;
+        feature_manager.reload()?;
 
         Ok(feature_manager)
     }
 
-    /// Load features from filesystem
-    fn load_features(&mut self) -> Result<()> {
-        let features_path = self.project_path.join(".fargin/features");
-        fs::create_dir_all(&features_path)?;
-
-        // Clear existing features
-        self.features.clear();
+    /// Pick up changes made to the feature files since they were last loaded
+    ///
+    /// Files whose modification time and size are unchanged are not re-read.
+    pub fn reload(&mut self) -> Result<()> {
+        let features_path = fargin_dir(&self.project_path).join("features");
 
         // Load markdown features, sorted by filename (which includes timestamp)
-        let mut feature_files: Vec<_> = fs::read_dir(&features_path)?
-            .filter_map(|entry| entry.ok())
-            .filter(|entry| entry.path().extension().and_then(|s| s.to_str()) == Some("md"))
-            .collect();
+        let mut feature_files: Vec<(PathBuf, fs::Metadata)> = Vec::new();
+        if features_path.is_dir() {
+            for entry in fs::read_dir(&features_path)?.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                if path.extension().and_then(|s| s.to_str()) == Some("md") {
+                    feature_files.push((path, entry.metadata()?));
+                }
+            }
+        }
 
         // Sort files by name to maintain chronological order
-        feature_files.sort_by_key(|a| a.file_name());
+        feature_files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // Forget features whose files were deleted
+        let current: BTreeSet<&PathBuf> = feature_files.iter().map(|(path, _)| path).collect();
+        let removed: Vec<PathBuf> = self
+            .loaded_files
+            .keys()
+            .filter(|path| !current.contains(path))
+            .cloned()
+            .collect();
+        for path in removed {
+            if let Some(loaded) = self.loaded_files.remove(&path) {
+                self.features.remove(&loaded.id);
+            }
+        }
+
+        let mut changed = Vec::new();
+        for (path, metadata) in feature_files {
+            let modified = metadata.modified()?;
+            let len = metadata.len();
+            if let Some(loaded) = self.loaded_files.get(&path) {
+                if loaded.modified == modified && loaded.len == len {
+                    continue;
+                }
+            }
+            changed.push((path, modified, len));
+        }
 
-        for entry in feature_files {
-            let content = fs::read_to_string(entry.path())?;
+        // Parse concurrently, then apply in filename order so the result does
+        // not depend on which thread finishes first
+        let paths: Vec<&Path> = changed.iter().map(|(path, _, _)| path.as_path()).collect();
+        let parsed = parse_feature_files(&paths, self.load_threads);
 
-            // Extract ID from filename
-            let id = entry
-                .path()
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .map(|s| s.to_string())
-                .context("Invalid feature filename")?;
-
-            // Extract name from content
-            let name = content
-                .lines()
-                .find(|line| line.starts_with("# Feature: "))
-                .map(|line| line.replace("# Feature: ", ""))
-                .unwrap_or_else(|| id.clone());
-
-            // Placeholder for parsing other fields
-            let feature = Feature {
-                id,
-                name,
-                description: None,
-                status: FeatureStatus::Proposed,
-                tags: Vec::new(),
-                priority: Priority::Medium,
-                assigned_to: None,
-                complexity: None,
-                created_at: Utc::now(),
-                updated_at: Utc::now(),
-                related_features: Vec::new(),
-                acceptance_criteria: Vec::new(),
+        for ((path, modified, len), feature) in changed.into_iter().zip(parsed) {
+            // One bad file should not stop every command; it is retried on the next reload
+            let feature = match feature {
+                Ok(feature) => feature,
+                Err(e) => {
+                    log::warn!("Skipping feature file {}: {}", path.display(), e);
+                    if let Some(previous) = self.loaded_files.remove(&path) {
+                        self.features.remove(&previous.id);
+                    }
+                    continue;
+                }
             };
+            self.parsed_files += 1;
 
+            if let Some(previous) = self.loaded_files.get(&path) {
+                if previous.id != feature.id {
+                    self.features.remove(&previous.id);
+                }
+            }
+            self.loaded_files.insert(
+                path,
+                LoadedFile {
+                    modified,
+                    len,
+                    id: feature.id.clone(),
+                },
+            );
             self.features.insert(feature.id.clone(), feature);
         }
 
@@ -176,20 +564,44 @@ impl FeatureManager {
     }
 
     /// Add a new feature
-    pub fn add_feature(
-        &mut self,
-        name: String,
-        description: Option<String>,
-        tags: Option<Vec<String>>,
-        priority: Option<Priority>,
-        assigned_to: Option<String>,
-    ) -> Result<String> {
+    pub fn add_feature(&mut self, new: NewFeature) -> Result<String> {
+        let NewFeature {
+            name,
+            description,
+            tags,
+            priority,
+            assigned_to,
+            acceptance_criteria,
+            related_features,
+            due_date,
+            complexity,
+        } = new;
+        if let Some(complexity) = complexity {
+            validate_complexity(complexity)?;
+        }
+
         // Generate unique ID
         let id = self.generate_feature_id(&name);
 
         // Validate feature doesn't already exist
         if self.features.contains_key(&id) {
-            return Err(anyhow::anyhow!("Feature with this name already exists"));
+            return Err(FarginError::validation(
+                "Feature with this name already exists",
+            ));
+        }
+
+        // Same-named features are allowed, but easy to mix up in listings
+        let existing: Vec<&str> = self
+            .find_by_name(&name)
+            .iter()
+            .map(|feature| feature.id.as_str())
+            .collect();
+        if !existing.is_empty() {
+            log::warn!(
+                "A feature named '{}' already exists: {}",
+                name.trim(),
+                existing.join(", ")
+            );
         }
 
         // Create feature
@@ -199,14 +611,16 @@ impl FeatureManager {
             name,
             description,
             status: FeatureStatus::Proposed,
-            tags: tags.unwrap_or_default(),
+            tags,
             priority: priority.unwrap_or(Priority::Medium),
             assigned_to,
-            complexity: None,
+            complexity,
             created_at: now,
             updated_at: now,
-            related_features: Vec::new(),
-            acceptance_criteria: Vec::new(),
+            related_features,
+            acceptance_criteria,
+            rank: None,
+            due_date,
         };
 
         // Save feature
@@ -220,13 +634,44 @@ impl FeatureManager {
 
     /// Update an existing feature
     pub fn update_feature(&mut self, id: &str, updates: FeatureUpdateRequest) -> Result<()> {
-        let feature = self.features.get_mut(id).context("Feature not found")?;
+        let before = self.apply_update(id, updates)?;
+        UndoJournal::new(&self.project_path).record(UndoOperation::FeatureUpdated { before })
+    }
+
+    /// Apply and save an update without journaling it, returning the feature as it was
+    fn apply_update(&mut self, id: &str, updates: FeatureUpdateRequest) -> Result<Feature> {
+        let feature = self
+            .features
+            .get_mut(id)
+            .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?;
+
+        if let Some(complexity) = updates.complexity {
+            validate_complexity(complexity)?;
+        }
+        if let Some(status) = updates.status {
+            if !updates.force && !feature.status.can_transition_to(status) {
+                return Err(FarginError::validation(format!(
+                    "Cannot move feature '{}' from {} to {} (use --force to override)",
+                    id, feature.status, status
+                )));
+            }
+        }
+
+        let before = feature.clone();
 
         // Update feature details
         if let Some(description) = updates.description {
             feature.description = Some(description);
         }
+        let mut status_change = None;
         if let Some(status) = updates.status {
+            if status != feature.status {
+                status_change = Some(StatusChange {
+                    timestamp: Utc::now(),
+                    from: feature.status,
+                    to: status,
+                });
+            }
             feature.status = status;
         }
         if let Some(tags) = updates.tags {
@@ -238,29 +683,547 @@ impl FeatureManager {
         if let Some(assigned_to) = updates.assigned_to {
             feature.assigned_to = Some(assigned_to);
         }
+        if let Some(complexity) = updates.complexity {
+            feature.complexity = Some(complexity);
+        }
+        if let Some(related_features) = updates.related_features {
+            feature.related_features = related_features;
+        }
+        if let Some(acceptance_criteria) = updates.acceptance_criteria {
+            feature.acceptance_criteria = acceptance_criteria;
+        }
+        if let Some(rank) = updates.rank {
+            feature.rank = Some(rank);
+        }
+        if let Some(due_date) = updates.due_date {
+            feature.due_date = Some(due_date);
+        }
+        feature.updated_at = Utc::now();
 
         // Save updated feature
         let feature_clone = feature.clone();
         self.save_feature(&feature_clone)?;
 
+        if let Some(change) = status_change {
+            self.append_history(id, &change)?;
+        }
+
+        Ok(before)
+    }
+
+    /// Path of the append-only status history kept next to a feature file
+    pub(crate) fn history_path(&self, id: &str) -> PathBuf {
+        fargin_dir(&self.project_path)
+            .join("features")
+            .join(format!("{}.history.jsonl", id))
+    }
+
+    /// Append one status change to a feature's history
+    fn append_history(&self, id: &str, change: &StatusChange) -> Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.history_path(id))?;
+        writeln!(file, "{}", serde_json::to_string(change)?)?;
         Ok(())
     }
 
+    /// Status changes recorded for a feature, oldest first
+    pub fn history(&self, id: &str) -> Result<Vec<StatusChange>> {
+        if !self.features.contains_key(id) {
+            return Err(FarginError::FeatureNotFound(id.to_string()));
+        }
+
+        let path = self.history_path(id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        fs::read_to_string(&path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| FarginError::Parse {
+                    path: path.clone(),
+                    message: format!("invalid history entry: {}", e),
+                })
+            })
+            .collect()
+    }
+
+    /// Raise a feature's priority by one level, returning the old and new priority
+    pub fn raise_priority(&mut self, id: &str) -> Result<(Priority, Priority)> {
+        self.shift_priority(id, Priority::raised)
+    }
+
+    /// Lower a feature's priority by one level, returning the old and new priority
+    pub fn lower_priority(&mut self, id: &str) -> Result<(Priority, Priority)> {
+        self.shift_priority(id, Priority::lowered)
+    }
+
+    fn shift_priority(
+        &mut self,
+        id: &str,
+        shift: fn(Priority) -> Priority,
+    ) -> Result<(Priority, Priority)> {
+        let old_priority = self
+            .get_feature(id)
+            .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?
+            .priority;
+        let new_priority = shift(old_priority);
+
+        self.update_feature(
+            id,
+            FeatureUpdateRequest {
+                priority: Some(new_priority),
+                ..Default::default()
+            },
+        )?;
+
+        Ok((old_priority, new_priority))
+    }
+
+    /// Move a feature directly above or below another feature in its status column
+    ///
+    /// The new rank is the midpoint between `other` and its neighbor, so
+    /// reordering never renumbers the rest of the column. Returns the new rank.
+    pub fn rank_feature(&mut self, id: &str, other: &str, placement: RankPlacement) -> Result<f64> {
+        if id == other {
+            return Err(FarginError::validation(
+                "A feature cannot be ranked relative to itself",
+            ));
+        }
+        self.get_feature(id)
+            .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?;
+        let anchor = self
+            .get_feature(other)
+            .ok_or_else(|| FarginError::FeatureNotFound(other.to_string()))?;
+        let column = anchor.status;
+
+        let column_ranks = |features: &HashMap<String, Feature>| -> Vec<f64> {
+            features
+                .values()
+                .filter(|feature| feature.status == column && feature.id != id)
+                .filter_map(|feature| feature.rank)
+                .collect()
+        };
+
+        // An unranked anchor joins the end of the ranked part of its column first
+        let mut operations = Vec::new();
+        let anchor_rank = match anchor.rank {
+            Some(rank) => rank,
+            None => {
+                let rank = column_ranks(&self.features)
+                    .into_iter()
+                    .fold(1.0, |next, rank| f64::max(next, rank + 1.0));
+                let before = self.apply_update(
+                    other,
+                    FeatureUpdateRequest {
+                        rank: Some(rank),
+                        ..Default::default()
+                    },
+                )?;
+                operations.push(UndoOperation::FeatureUpdated { before });
+                rank
+            }
+        };
+
+        let ranks = column_ranks(&self.features);
+        let rank = match placement {
+            RankPlacement::Above => ranks
+                .into_iter()
+                .filter(|rank| *rank < anchor_rank)
+                .reduce(f64::max)
+                .map(|neighbor| (neighbor + anchor_rank) / 2.0)
+                .unwrap_or(anchor_rank - 1.0),
+            RankPlacement::Below => ranks
+                .into_iter()
+                .filter(|rank| *rank > anchor_rank)
+                .reduce(f64::min)
+                .map(|neighbor| (neighbor + anchor_rank) / 2.0)
+                .unwrap_or(anchor_rank + 1.0),
+        };
+
+        let before = self.apply_update(
+            id,
+            FeatureUpdateRequest {
+                rank: Some(rank),
+                ..Default::default()
+            },
+        )?;
+        operations.push(UndoOperation::FeatureUpdated { before });
+        UndoJournal::new(&self.project_path).record(UndoOperation::Batch {
+            summary: format!("Ranked feature {}", id),
+            operations,
+        })?;
+
+        Ok(rank)
+    }
+
     /// List features with optional filtering
     pub fn list_features(
         &self,
         tag: Option<&str>,
         status: Option<FeatureStatus>,
         priority: Option<Priority>,
+        assigned_to: Option<&str>,
     ) -> Vec<&Feature> {
-        self.features
+        self.list_matching(&FeatureFilter {
+            tags: tag.map(str::to_string).into_iter().collect(),
+            status,
+            priority,
+            assigned_to: assigned_to.map(str::to_string),
+            ..Default::default()
+        })
+    }
+
+    /// List features matching a filter, oldest first
+    pub fn list_matching(&self, filter: &FeatureFilter) -> Vec<&Feature> {
+        let mut features: Vec<&Feature> = self
+            .features
+            .values()
+            .filter(|feature| filter.matches(feature))
+            .collect();
+        // The cache is a HashMap, so give listings a stable order
+        sort_features(&mut features, FeatureSort::Created);
+        features
+    }
+
+    /// Apply the same update to every feature matching `filter`
+    ///
+    /// Returns how many features were updated.
+    pub fn update_matching(
+        &mut self,
+        filter: FeatureFilter,
+        updates: FeatureUpdateRequest,
+    ) -> Result<usize> {
+        let mut ids: Vec<String> = self
+            .features
+            .values()
+            .filter(|feature| filter.matches(feature))
+            .map(|feature| feature.id.clone())
+            .collect();
+        ids.sort();
+
+        // Check every transition up front so a bad one doesn't leave the batch half applied
+        if let (Some(status), false) = (updates.status, updates.force) {
+            let blocked: Vec<&str> = ids
+                .iter()
+                .filter(|id| !self.features[id.as_str()].status.can_transition_to(status))
+                .map(String::as_str)
+                .collect();
+            if !blocked.is_empty() {
+                return Err(FarginError::validation(format!(
+                    "Cannot move {} to {} (use --force to override): {}",
+                    if blocked.len() == 1 {
+                        "feature"
+                    } else {
+                        "features"
+                    },
+                    status,
+                    blocked.join(", ")
+                )));
+            }
+        }
+
+        let mut operations = Vec::new();
+        for id in &ids {
+            let before = self.apply_update(id, updates.clone())?;
+            operations.push(UndoOperation::FeatureUpdated { before });
+        }
+        if !operations.is_empty() {
+            UndoJournal::new(&self.project_path).record(UndoOperation::Batch {
+                summary: format!("Updated {} features", operations.len()),
+                operations,
+            })?;
+        }
+
+        Ok(ids.len())
+    }
+
+    /// Case-insensitive full-text search, most relevant features first
+    ///
+    /// Matches in the name weigh more than matches in tags, which weigh more
+    /// than matches in the description or acceptance criteria.
+    pub fn search(&self, query: &str) -> Vec<&Feature> {
+        let query = query.to_lowercase();
+        let matches = |text: &str| text.to_lowercase().contains(&query);
+
+        let mut results: Vec<(usize, &Feature)> = self
+            .features
+            .values()
+            .map(|feature| {
+                let mut score = 0;
+                if matches(&feature.name) {
+                    score += 4;
+                }
+                if feature.tags.iter().any(|tag| matches(tag)) {
+                    score += 2;
+                }
+                if feature.description.as_deref().is_some_and(matches) {
+                    score += 1;
+                }
+                if feature
+                    .acceptance_criteria
+                    .iter()
+                    .any(|criterion| matches(criterion))
+                {
+                    score += 1;
+                }
+                (score, feature)
+            })
+            .filter(|(score, _)| *score > 0)
+            .collect();
+
+        results.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.id.cmp(&b.1.id)));
+        results.into_iter().map(|(_, feature)| feature).collect()
+    }
+
+    /// Roll up feature counts and complexity across the project
+    pub fn statistics(&self) -> FeatureStats {
+        let mut stats = FeatureStats {
+            total: self.features.len(),
+            ..Default::default()
+        };
+        let mut complexities = Vec::new();
+
+        for feature in self.features.values() {
+            *stats.by_status.entry(feature.status).or_insert(0) += 1;
+            *stats.by_priority.entry(feature.priority).or_insert(0) += 1;
+            if feature.assigned_to.is_none() {
+                stats.unassigned += 1;
+            }
+            if let Some(complexity) = feature.complexity {
+                complexities.push(f64::from(complexity));
+            }
+        }
+        stats.overdue = self.overdue().len();
+        stats.duplicate_names = self.duplicate_names();
+
+        if !complexities.is_empty() {
+            stats.average_complexity =
+                Some(complexities.iter().sum::<f64>() / complexities.len() as f64);
+        }
+
+        stats
+    }
+
+    /// Unfinished features whose due date has passed, most overdue first
+    ///
+    /// Implemented and deprecated features are never overdue.
+    pub fn overdue(&self) -> Vec<&Feature> {
+        let now = Utc::now();
+        let mut overdue: Vec<&Feature> = self
+            .features
             .values()
             .filter(|feature| {
-                tag.is_none_or(|t| feature.tags.contains(&t.to_string()))
-                    && status.is_none_or(|s| feature.status == s)
-                    && priority.is_none_or(|p| feature.priority == p)
+                !matches!(
+                    feature.status,
+                    FeatureStatus::Implemented | FeatureStatus::Deprecated
+                )
             })
-            .collect()
+            .filter(|feature| feature.due_date.is_some_and(|due| due < now))
+            .collect();
+        overdue.sort_by(|a, b| a.due_date.cmp(&b.due_date).then_with(|| a.id.cmp(&b.id)));
+        overdue
+    }
+
+    /// Count features per value of a field, most common values first
+    ///
+    /// For tags, a feature counts toward each of its tags; features
+    /// without an assignee are counted as "Unassigned".
+    pub fn count_by(&self, field: CountField) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+
+        for feature in self.features.values() {
+            let values = match field {
+                CountField::Status => vec![feature.status.to_string()],
+                CountField::Priority => vec![format!("{:?}", feature.priority)],
+                CountField::Tag => feature.tags.clone(),
+                CountField::Assignee => vec![feature
+                    .assigned_to
+                    .clone()
+                    .unwrap_or_else(|| "Unassigned".to_string())],
+            };
+
+            for value in values {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+
+    /// Order features so every feature comes after the features it depends on
+    ///
+    /// A feature's `related_features` are treated as its dependencies.
+    /// Implemented features count as satisfied and are left out of the plan,
+    /// as are dependencies outside the filtered set.
+    pub fn implementation_order(&self, tag: Option<&str>) -> Result<Vec<&Feature>> {
+        let pending: HashMap<&str, &Feature> = self
+            .list_features(tag, None, None, None)
+            .into_iter()
+            .filter(|feature| feature.status != FeatureStatus::Implemented)
+            .map(|feature| (feature.id.as_str(), feature))
+            .collect();
+
+        // Count unmet dependencies and record who is waiting on each feature
+        let mut unmet: HashMap<&str, usize> = HashMap::new();
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (id, feature) in &pending {
+            let dependencies: BTreeSet<&str> = feature
+                .related_features
+                .iter()
+                .map(String::as_str)
+                .filter(|dependency| pending.contains_key(dependency))
+                .collect();
+
+            unmet.insert(id, dependencies.len());
+            for dependency in dependencies {
+                dependents.entry(dependency).or_default().push(id);
+            }
+        }
+
+        // Kahn's algorithm; the ready set is ordered by ID for a stable plan
+        let mut ready: BTreeSet<&str> = unmet
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        let mut order = Vec::with_capacity(pending.len());
+
+        while let Some(id) = ready.pop_first() {
+            order.push(pending[id]);
+            for dependent in dependents.get(id).into_iter().flatten() {
+                let count = unmet.get_mut(dependent).expect("dependent is pending");
+                *count -= 1;
+                if *count == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+
+        if order.len() < pending.len() {
+            let mut blocked: Vec<&str> = unmet
+                .into_iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(id, _)| id)
+                .collect();
+            blocked.sort();
+            return Err(FarginError::validation(format!(
+                "Dependency cycle prevents ordering: {}",
+                blocked.join(", ")
+            )));
+        }
+
+        Ok(order)
+    }
+
+    /// Find dependency cycles in the `related_features` graph
+    ///
+    /// Each cycle is reported once, as the IDs along the cycle starting from
+    /// the feature where the depth-first search entered it.
+    pub fn detect_dependency_cycles(&self) -> Vec<Vec<String>> {
+        fn visit<'a>(
+            id: &'a str,
+            features: &'a HashMap<String, Feature>,
+            visited: &mut BTreeSet<&'a str>,
+            stack: &mut Vec<&'a str>,
+            cycles: &mut Vec<Vec<String>>,
+        ) {
+            visited.insert(id);
+            stack.push(id);
+
+            for dependency in &features[id].related_features {
+                if !features.contains_key(dependency) {
+                    continue;
+                }
+                if let Some(start) = stack.iter().position(|on_stack| on_stack == dependency) {
+                    cycles.push(stack[start..].iter().map(|id| id.to_string()).collect());
+                } else if !visited.contains(dependency.as_str()) {
+                    visit(dependency, features, visited, stack, cycles);
+                }
+            }
+
+            stack.pop();
+        }
+
+        let mut ids: Vec<&str> = self.features.keys().map(String::as_str).collect();
+        ids.sort();
+
+        let mut visited = BTreeSet::new();
+        let mut cycles = Vec::new();
+        for id in ids {
+            if !visited.contains(id) {
+                visit(
+                    id,
+                    &self.features,
+                    &mut visited,
+                    &mut Vec::new(),
+                    &mut cycles,
+                );
+            }
+        }
+
+        cycles
+    }
+
+    /// Render features and their `related_features` links as a Mermaid `graph TD`
+    ///
+    /// Nodes are labelled by name and styled by status. Links to unknown IDs
+    /// are left out with a warning.
+    pub fn to_mermaid(&self) -> String {
+        let mut features: Vec<&Feature> = self.features.values().collect();
+        features.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut graph = String::from("graph TD\n");
+        for feature in &features {
+            graph.push_str(&format!(
+                "    {}[\"{}\"]:::{}\n",
+                mermaid_node_id(&feature.id),
+                feature.name.replace('"', "#quot;"),
+                mermaid_status_class(feature.status)
+            ));
+        }
+
+        for feature in &features {
+            for related in &feature.related_features {
+                if !self.features.contains_key(related) {
+                    log::warn!(
+                        "Feature {} is related to unknown feature {}; skipping edge",
+                        feature.id,
+                        related
+                    );
+                    continue;
+                }
+                graph.push_str(&format!(
+                    "    {} --> {}\n",
+                    mermaid_node_id(&feature.id),
+                    mermaid_node_id(related)
+                ));
+            }
+        }
+
+        for (status, style) in [
+            (FeatureStatus::Proposed, "fill:#f5f5f5,stroke:#9e9e9e"),
+            (FeatureStatus::InProgress, "fill:#fff4ce,stroke:#d4a106"),
+            (FeatureStatus::Implemented, "fill:#dff6dd,stroke:#107c10"),
+            (FeatureStatus::Blocked, "fill:#fde7e9,stroke:#c50f1f"),
+            (
+                FeatureStatus::Deprecated,
+                "fill:#e0e0e0,stroke:#616161,color:#616161",
+            ),
+        ] {
+            graph.push_str(&format!(
+                "    classDef {} {}\n",
+                mermaid_status_class(status),
+                style
+            ));
+        }
+
+        graph
     }
 
     /// Generate a unique feature ID
@@ -279,24 +1242,107 @@ impl FeatureManager {
 
     /// Save feature to filesystem
     fn save_feature(&self, feature: &Feature) -> Result<()> {
-        let features_path = self.project_path.join(".fargin/features");
+        let features_path = fargin_dir(&self.project_path).join("features");
         fs::create_dir_all(&features_path)?;
 
-        // Convert feature to markdown
-        let markdown_content = format!(
-            "# Feature: {}\n\n\
-            ## Details\n\
-            - **ID**: {}\n\
-            - **Status**: {:?}\n\
-            - **Priority**: {:?}\n\
-            - **Assigned To**: {}\n\
-            - **Created At**: {}\n\
-            - **Updated At**: {}\n\n\
-            ## Description\n\
-            {}\n\n\
-            ## Acceptance Criteria\n\
-            {}\n\n\
-            ## Related Features\n\
+        let markdown_content = Self::feature_to_file(feature)?;
+
+        let file_path = features_path.join(format!("{}.md", feature.id));
+        fs::write(file_path, markdown_content)?;
+
+        Ok(())
+    }
+
+    /// Convert a feature to the file stored on disk: YAML front matter plus markdown body
+    pub(crate) fn feature_to_file(feature: &Feature) -> Result<String> {
+        let front_matter = serde_yaml::to_string(&FeatureFrontMatter {
+            id: feature.id.clone(),
+            name: Some(feature.name.clone()),
+            status: feature.status,
+            priority: feature.priority,
+            tags: feature.tags.clone(),
+            assigned_to: feature.assigned_to.clone(),
+            complexity: feature.complexity,
+            created_at: feature.created_at,
+            updated_at: feature.updated_at,
+            related_features: feature.related_features.clone(),
+            acceptance_criteria: feature.acceptance_criteria.clone(),
+            rank: feature.rank,
+            due_date: feature.due_date,
+        })?;
+
+        Ok(format!(
+            "---\n{}---\n\n{}",
+            front_matter,
+            Self::feature_to_markdown(feature)
+        ))
+    }
+
+    /// Parse a feature file, preferring YAML front matter over the markdown body
+    ///
+    /// Files written before front matter was introduced are read with the
+    /// legacy markdown parser.
+    fn feature_from_file(id: &str, content: &str) -> Result<Feature, serde_yaml::Error> {
+        let Some((yaml, body)) = content
+            .strip_prefix("---\n")
+            .and_then(|rest| rest.split_once("\n---\n"))
+        else {
+            return Ok(Self::feature_from_markdown(id, content));
+        };
+
+        let front_matter: FeatureFrontMatter = serde_yaml::from_str(yaml)?;
+
+        // The body layout is fixed, so the prose is cut out of it verbatim
+        // rather than re-parsed, which would misread headings inside a description
+        let name = front_matter.name.unwrap_or_else(|| {
+            body.lines()
+                .find_map(|line| line.strip_prefix("# Feature: "))
+                .map(|name| name.trim().to_string())
+                .unwrap_or_else(|| id.to_string())
+        });
+        let description = body
+            .split_once("\n## Description\n")
+            .and_then(|(_, rest)| rest.rsplit_once("\n\n## Acceptance Criteria\n"))
+            .map(|(description, _)| description)
+            .filter(|description| *description != "No description")
+            .map(str::to_string);
+
+        Ok(Feature {
+            id: front_matter.id,
+            name,
+            description,
+            status: front_matter.status,
+            tags: front_matter.tags,
+            priority: front_matter.priority,
+            assigned_to: front_matter.assigned_to,
+            complexity: front_matter.complexity,
+            created_at: front_matter.created_at,
+            updated_at: front_matter.updated_at,
+            related_features: front_matter.related_features,
+            acceptance_criteria: front_matter.acceptance_criteria,
+            rank: front_matter.rank,
+            due_date: front_matter.due_date,
+        })
+    }
+
+    /// Convert a feature to the human-readable markdown body
+    fn feature_to_markdown(feature: &Feature) -> String {
+        format!(
+            "# Feature: {}\n\n\
+            ## Details\n\
+            - **ID**: {}\n\
+            - **Status**: {:?}\n\
+            - **Priority**: {:?}\n\
+            - **Assigned To**: {}\n\
+            - **Created At**: {}\n\
+            - **Updated At**: {}\n\
+            - **Rank**: {}\n\
+            - **Due Date**: {}\n\n\
+            ## Description\n\
+            {}\n\n\
+            ## Acceptance Criteria\n\
+            {}\n\n\
+            ## Related Features\n\
             {}\n\n\
             ## Tags\n\
             {}",
@@ -307,16 +1353,206 @@ impl FeatureManager {
             feature.assigned_to.as_deref().unwrap_or("Unassigned"),
             feature.created_at.to_rfc3339(),
             feature.updated_at.to_rfc3339(),
+            feature
+                .rank
+                .map(|rank| rank.to_string())
+                .unwrap_or_else(|| "Unranked".to_string()),
+            feature
+                .due_date
+                .map(|due| due.to_rfc3339())
+                .unwrap_or_else(|| "None".to_string()),
             feature.description.as_deref().unwrap_or("No description"),
-            feature.acceptance_criteria.join("\n- "),
+            feature
+                .acceptance_criteria
+                .iter()
+                .map(|criterion| format!("- {}", criterion))
+                .collect::<Vec<_>>()
+                .join("\n"),
             feature.related_features.join(", "),
             feature.tags.join(", ")
-        );
+        )
+    }
 
-        let file_path = features_path.join(format!("{}.md", feature.id));
-        fs::write(file_path, markdown_content)?;
+    /// Parse a feature from the markdown body written by `feature_to_markdown`
+    ///
+    /// Missing or unreadable fields fall back to the defaults used for new features.
+    fn feature_from_markdown(id: &str, content: &str) -> Feature {
+        let now = Utc::now();
+        let mut feature = Feature {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            status: FeatureStatus::Proposed,
+            tags: Vec::new(),
+            priority: Priority::Medium,
+            assigned_to: None,
+            complexity: None,
+            created_at: now,
+            updated_at: now,
+            related_features: Vec::new(),
+            acceptance_criteria: Vec::new(),
+            rank: None,
+            due_date: None,
+        };
 
-        Ok(())
+        // Group body lines under their `## ` section heading
+        let mut sections: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut current = "";
+        let mut named = false;
+        for line in content.lines() {
+            if let (false, Some(name)) = (named, line.strip_prefix("# Feature: ")) {
+                feature.name = name.trim().to_string();
+                named = true;
+            } else if let Some(heading) = line.strip_prefix("## ") {
+                current = heading.trim();
+            } else if !current.is_empty() && !line.trim().is_empty() {
+                sections.entry(current).or_default().push(line.trim());
+            }
+        }
+
+        let parse_time = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|time| time.with_timezone(&Utc))
+                .ok()
+        };
+
+        for line in sections.get("Details").into_iter().flatten() {
+            let Some((key, value)) = line
+                .strip_prefix("- **")
+                .and_then(|rest| rest.split_once("**: "))
+            else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key {
+                "Status" => {
+                    if let Ok(status) = value.parse() {
+                        feature.status = status;
+                    }
+                }
+                "Priority" => {
+                    if let Ok(priority) = value.parse() {
+                        feature.priority = priority;
+                    }
+                }
+                "Assigned To" if value != "Unassigned" => {
+                    feature.assigned_to = Some(value.to_string());
+                }
+                "Created At" => feature.created_at = parse_time(value).unwrap_or(now),
+                "Updated At" => feature.updated_at = parse_time(value).unwrap_or(now),
+                "Rank" => feature.rank = value.parse().ok(),
+                "Due Date" => feature.due_date = parse_time(value),
+                _ => {}
+            }
+        }
+
+        if let Some(lines) = sections.get("Description") {
+            let description = lines.join("\n");
+            if description != "No description" {
+                feature.description = Some(description);
+            }
+        }
+
+        feature.acceptance_criteria = sections
+            .get("Acceptance Criteria")
+            .into_iter()
+            .flatten()
+            .map(|line| line.trim_start_matches("- ").to_string())
+            .collect();
+
+        let list = |section: &str| -> Vec<String> {
+            sections
+                .get(section)
+                .into_iter()
+                .flatten()
+                .flat_map(|line| line.split(','))
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        feature.related_features = list("Related Features");
+        feature.tags = list("Tags");
+
+        feature
+    }
+
+    /// Export filtered features as a single markdown document with a table of contents
+    pub fn export_markdown(
+        &self,
+        tag: Option<&str>,
+        status: Option<FeatureStatus>,
+        priority: Option<Priority>,
+    ) -> String {
+        let mut features = self.list_features(tag, status, priority, None);
+
+        // Feature IDs start with a timestamp, so this keeps chronological order
+        features.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut document = String::from("# Feature Specification\n\n");
+
+        if features.is_empty() {
+            document.push_str("No features found.\n");
+            return document;
+        }
+
+        document.push_str("## Table of Contents\n\n");
+        for feature in &features {
+            document.push_str(&format!(
+                "- [{}](#{}) ({})\n",
+                feature.name,
+                feature_anchor(&feature.id),
+                feature.status
+            ));
+        }
+
+        for feature in &features {
+            document.push_str(&format!(
+                "\n---\n\n<a id=\"{}\"></a>\n\n{}\n",
+                feature_anchor(&feature.id),
+                Self::feature_to_markdown(feature)
+            ));
+        }
+
+        document
+    }
+
+    /// All features, oldest first
+    ///
+    /// ```rust
+    /// use fargin::features::{FeatureManager, NewFeature};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let project = tempfile::tempdir()?;
+    ///     let mut manager = FeatureManager::new(project.path())?;
+    ///     for name in ["Login", "Search"] {
+    ///         manager.add_feature(NewFeature {
+    ///             name: name.to_string(),
+    ///             ..Default::default()
+    ///         })?;
+    ///     }
+    ///
+    ///     let names: Vec<&str> = manager.features().map(|f| f.name.as_str()).collect();
+    ///     assert_eq!(names, ["Login", "Search"]);
+    ///     assert_eq!(manager.len(), 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn features(&self) -> impl Iterator<Item = &Feature> {
+        let mut features: Vec<&Feature> = self.features.values().collect();
+        sort_features(&mut features, FeatureSort::Created);
+        features.into_iter()
+    }
+
+    /// Number of features
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Whether the project has no features
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
     }
 
     /// Get a specific feature by ID
@@ -324,24 +1560,525 @@ impl FeatureManager {
         self.features.get(id)
     }
 
+    /// Features with this name, ignoring case and surrounding whitespace, oldest first
+    pub fn find_by_name(&self, name: &str) -> Vec<&Feature> {
+        let name = name.trim();
+        let mut matches: Vec<&Feature> = self
+            .features
+            .values()
+            .filter(|feature| feature.name.trim().eq_ignore_ascii_case(name))
+            .collect();
+        sort_features(&mut matches, FeatureSort::Created);
+        matches
+    }
+
+    /// IDs of features that share a name with another feature, keyed by name
+    pub fn duplicate_names(&self) -> BTreeMap<String, Vec<String>> {
+        let mut by_name: BTreeMap<String, Vec<&Feature>> = BTreeMap::new();
+        for feature in self.features.values() {
+            by_name
+                .entry(feature.name.trim().to_lowercase())
+                .or_default()
+                .push(feature);
+        }
+
+        by_name
+            .into_values()
+            .filter(|features| features.len() > 1)
+            .map(|mut features| {
+                sort_features(&mut features, FeatureSort::Created);
+                let name = features[0].name.trim().to_string();
+                (name, features.iter().map(|f| f.id.clone()).collect())
+            })
+            .collect()
+    }
+
     /// Delete a feature
     pub fn delete_feature(&mut self, id: &str) -> Result<()> {
         // Remove from filesystem
-        let feature_path = self
-            .project_path
-            .join(".fargin/features")
+        let feature_path = fargin_dir(&self.project_path)
+            .join("features")
             .join(format!("{}.md", id));
 
         if feature_path.exists() {
             fs::remove_file(feature_path)?;
         }
 
-        // Remove from in-memory cache
-        self.features.remove(id);
+        // Remove from in-memory cache, journaling the full feature so the removal can be undone
+        if let Some(feature) = self.features.remove(id) {
+            UndoJournal::new(&self.project_path)
+                .record(UndoOperation::FeatureRemoved { feature })?;
+        }
+
+        Ok(())
+    }
 
+    /// Write a previously captured feature back, replacing any current version
+    pub fn restore_feature(&mut self, feature: Feature) -> Result<()> {
+        self.save_feature(&feature)?;
+        self.features.insert(feature.id.clone(), feature);
         Ok(())
     }
 
+    /// Serialize every feature as a JSON array, oldest first
+    pub fn export_json(&self) -> Result<String> {
+        let mut features: Vec<&Feature> = self.features.values().collect();
+        // Feature IDs start with a timestamp, so this keeps chronological order
+        features.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(serde_json::to_string_pretty(&features)?)
+    }
+
+    /// Recreate features from a JSON array written by `export_json`
+    ///
+    /// IDs that already exist are handled according to `on_conflict`. When a
+    /// feature is renamed, references to it from other imported features
+    /// follow it to the new ID.
+    pub fn import_json(
+        &mut self,
+        json: &str,
+        on_conflict: ConflictPolicy,
+    ) -> Result<ImportSummary> {
+        let incoming: Vec<Feature> = serde_json::from_str(json)
+            .map_err(|e| FarginError::validation(format!("Invalid feature export: {}", e)))?;
+
+        for feature in &incoming {
+            validate_feature_id(&feature.id)?;
+        }
+
+        let mut summary = ImportSummary::default();
+        let mut renames: HashMap<String, String> = HashMap::new();
+        let mut to_write = Vec::new();
+
+        for mut feature in incoming {
+            if self.features.contains_key(&feature.id) {
+                match on_conflict {
+                    ConflictPolicy::Skip => {
+                        summary.skipped.push(feature.id);
+                        continue;
+                    }
+                    ConflictPolicy::Overwrite => {}
+                    ConflictPolicy::Rename => {
+                        let new_id = self.unused_import_id(&feature.id, &renames);
+                        renames.insert(feature.id.clone(), new_id.clone());
+                        summary.renamed.push((feature.id.clone(), new_id.clone()));
+                        feature.id = new_id;
+                    }
+                }
+            }
+            to_write.push(feature);
+        }
+
+        let mut operations = Vec::new();
+        for mut feature in to_write {
+            for related in &mut feature.related_features {
+                if let Some(new_id) = renames.get(related) {
+                    *related = new_id.clone();
+                }
+            }
+
+            self.save_feature(&feature)?;
+            summary.imported.push(feature.id.clone());
+            if let Some(before) = self.features.insert(feature.id.clone(), feature) {
+                operations.push(UndoOperation::FeatureUpdated { before });
+            }
+        }
+        if !operations.is_empty() {
+            UndoJournal::new(&self.project_path).record(UndoOperation::Batch {
+                summary: format!("Overwrote {} features on import", operations.len()),
+                operations,
+            })?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Create a feature for each `- [ ]` / `- [x]` item in a markdown checklist
+    ///
+    /// Unchecked items become `Proposed` and checked ones `Implemented`.
+    /// Trailing `#tag` tokens become tags. Returns the new feature IDs in
+    /// checklist order.
+    pub fn import_checklist(&mut self, markdown: &str) -> Result<Vec<String>> {
+        let mut ids = Vec::new();
+
+        for item in markdown.lines().filter_map(parse_checklist_item) {
+            let id = self
+                .add_feature(NewFeature {
+                    name: item.name.clone(),
+                    tags: item.tags,
+                    ..Default::default()
+                })
+                .map_err(|e| match e {
+                    FarginError::Validation(message) => FarginError::Validation(format!(
+                        "Failed to import checklist item {:?}: {}",
+                        item.name, message
+                    )),
+                    other => other,
+                })?;
+
+            if item.checked {
+                let feature = self
+                    .features
+                    .get_mut(&id)
+                    .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?;
+                feature.status = FeatureStatus::Implemented;
+                let feature_clone = feature.clone();
+                self.save_feature(&feature_clone)?;
+            }
+            ids.push(id);
+        }
+
+        Ok(ids)
+    }
+
+    /// Pick an ID for an imported feature that clashes with `id`
+    fn unused_import_id(&self, id: &str, renames: &HashMap<String, String>) -> String {
+        let taken = |candidate: &str| {
+            self.features.contains_key(candidate) || renames.values().any(|used| used == candidate)
+        };
+
+        let mut candidate = format!("{}_imported", id);
+        let mut n = 2;
+        while taken(&candidate) {
+            candidate = format!("{}_imported_{}", id, n);
+            n += 1;
+        }
+        candidate
+    }
+
+    /// Give a feature a new name, keeping its ID so references stay valid
+    pub fn rename(&mut self, id: &str, new_name: &str) -> Result<()> {
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err(FarginError::validation("Feature name cannot be empty"));
+        }
+
+        let feature = self
+            .features
+            .get_mut(id)
+            .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?;
+        let before = feature.clone();
+
+        feature.name = new_name.to_string();
+        feature.updated_at = Utc::now();
+        let feature_clone = feature.clone();
+        self.save_feature(&feature_clone)?;
+        UndoJournal::new(&self.project_path).record(UndoOperation::FeatureUpdated { before })
+    }
+
+    /// Move a feature to a new ID, rewriting every `related_features` reference
+    ///
+    /// Returns the IDs of the features whose references were rewritten.
+    pub fn reassign_id(&mut self, id: &str, new_id: &str) -> Result<Vec<String>> {
+        validate_feature_id(new_id)?;
+        if self.features.contains_key(new_id) {
+            return Err(FarginError::validation(format!(
+                "Feature {} already exists",
+                new_id
+            )));
+        }
+        let mut feature = self
+            .features
+            .remove(id)
+            .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?;
+
+        let mut operations = vec![UndoOperation::FeatureMoved {
+            from: feature.clone(),
+            to: new_id.to_string(),
+        }];
+
+        feature.id = new_id.to_string();
+        feature.updated_at = Utc::now();
+        self.save_feature(&feature)?;
+        self.features.insert(new_id.to_string(), feature);
+
+        let features_path = fargin_dir(&self.project_path).join("features");
+        fs::remove_file(features_path.join(format!("{}.md", id)))?;
+        let history = self.history_path(id);
+        if history.exists() {
+            fs::rename(history, self.history_path(new_id))?;
+        }
+
+        let mut referencing: Vec<String> = self
+            .features
+            .values()
+            .filter(|feature| feature.related_features.iter().any(|r| r == id))
+            .map(|feature| feature.id.clone())
+            .collect();
+        referencing.sort();
+        for referencing_id in &referencing {
+            let feature = self
+                .features
+                .get_mut(referencing_id)
+                .expect("referencing feature exists");
+            operations.push(UndoOperation::FeatureUpdated {
+                before: feature.clone(),
+            });
+            for related in &mut feature.related_features {
+                if related == id {
+                    *related = new_id.to_string();
+                }
+            }
+            feature.updated_at = Utc::now();
+            let feature_clone = feature.clone();
+            self.save_feature(&feature_clone)?;
+        }
+
+        UndoJournal::new(&self.project_path).record(UndoOperation::Batch {
+            summary: format!("Changed feature ID {} to {}", id, new_id),
+            operations,
+        })?;
+
+        Ok(referencing)
+    }
+
+    /// Walk through matching features one at a time, applying single-key actions
+    ///
+    /// Each action is read as one line from `input` and applied immediately:
+    /// `i` in progress, `b` block, `d` deprecate, `p`/`P` lower/raise priority,
+    /// `s` skip and `q` quit. End of input also quits.
+    pub fn triage<R: BufRead, W: Write>(
+        &mut self,
+        tag: Option<&str>,
+        status: Option<FeatureStatus>,
+        priority: Option<Priority>,
+        input: &mut R,
+        output: &mut W,
+    ) -> Result<TriageSummary> {
+        let mut ids: Vec<String> = self
+            .list_features(tag, status, priority, None)
+            .into_iter()
+            .map(|feature| feature.id.clone())
+            .collect();
+        // Feature IDs start with a timestamp, so oldest features come first
+        ids.sort();
+
+        let mut summary = TriageSummary::default();
+        let total = ids.len();
+
+        'features: for (index, id) in ids.iter().enumerate() {
+            let feature = self
+                .get_feature(id)
+                .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?;
+            writeln!(
+                output,
+                "\n[{}/{}] {} ({})",
+                index + 1,
+                total,
+                feature.name,
+                id
+            )?;
+            writeln!(
+                output,
+                "  Status: {}  Priority: {:?}  Assigned To: {}",
+                feature.status,
+                feature.priority,
+                feature.assigned_to.as_deref().unwrap_or("Unassigned")
+            )?;
+            if let Some(description) = &feature.description {
+                writeln!(output, "  {}", description)?;
+            }
+
+            loop {
+                write!(
+                    output,
+                    "  [i]n progress [b]lock [d]eprecate [p/P] lower/raise priority [s]kip [q]uit > "
+                )?;
+                output.flush()?;
+
+                let mut line = String::new();
+                if input.read_line(&mut line)? == 0 {
+                    break 'features;
+                }
+
+                let update = match line.trim() {
+                    "i" => FeatureUpdateRequest {
+                        status: Some(FeatureStatus::InProgress),
+                        ..Default::default()
+                    },
+                    "b" => FeatureUpdateRequest {
+                        status: Some(FeatureStatus::Blocked),
+                        ..Default::default()
+                    },
+                    "d" => FeatureUpdateRequest {
+                        status: Some(FeatureStatus::Deprecated),
+                        ..Default::default()
+                    },
+                    "p" | "P" => {
+                        let (old, new) = if line.trim() == "p" {
+                            self.lower_priority(id)?
+                        } else {
+                            self.raise_priority(id)?
+                        };
+                        writeln!(output, "  Priority: {:?} -> {:?}", old, new)?;
+                        summary.updated += 1;
+                        continue 'features;
+                    }
+                    "s" => {
+                        summary.skipped += 1;
+                        continue 'features;
+                    }
+                    "q" => break 'features,
+                    other => {
+                        writeln!(output, "  Unknown action: {:?}", other)?;
+                        continue;
+                    }
+                };
+
+                let new_status = update.status;
+                if let Some(new_status) = new_status {
+                    let current = self
+                        .get_feature(id)
+                        .ok_or_else(|| FarginError::FeatureNotFound(id.to_string()))?
+                        .status;
+                    if !current.can_transition_to(new_status) {
+                        writeln!(output, "  Cannot move from {} to {}", current, new_status)?;
+                        continue;
+                    }
+                }
+                self.update_feature(id, update)?;
+                if let Some(new_status) = new_status {
+                    writeln!(output, "  Status: {}", new_status)?;
+                }
+                summary.updated += 1;
+                continue 'features;
+            }
+        }
+
+        summary.remaining = total - summary.updated - summary.skipped;
+        Ok(summary)
+    }
+
+    /// Merge a duplicate feature into another one and archive the duplicate
+    ///
+    /// Tags and acceptance criteria are unioned, the more urgent priority wins
+    /// and descriptions are concatenated. Assignee and status come from the
+    /// side chosen by `prefer`. Features that referenced the dropped feature
+    /// are re-pointed at the kept one.
+    pub fn merge_features(
+        &mut self,
+        keep_id: &str,
+        drop_id: &str,
+        prefer: MergePreference,
+    ) -> Result<MergeSummary> {
+        if keep_id == drop_id {
+            return Err(FarginError::validation(
+                "A feature cannot be merged into itself",
+            ));
+        }
+        let keep = self
+            .get_feature(keep_id)
+            .ok_or_else(|| FarginError::FeatureNotFound(keep_id.to_string()))?
+            .clone();
+        let dropped = self
+            .get_feature(drop_id)
+            .ok_or_else(|| FarginError::FeatureNotFound(drop_id.to_string()))?
+            .clone();
+
+        let mut merged = keep.clone();
+
+        let added_tags: Vec<String> = dropped
+            .tags
+            .iter()
+            .filter(|tag| !keep.tags.contains(tag))
+            .cloned()
+            .collect();
+        merged.tags.extend(added_tags.iter().cloned());
+
+        let added_criteria: Vec<String> = dropped
+            .acceptance_criteria
+            .iter()
+            .filter(|criterion| !keep.acceptance_criteria.contains(criterion))
+            .cloned()
+            .collect();
+        merged
+            .acceptance_criteria
+            .extend(added_criteria.iter().cloned());
+
+        // Priority ordering puts the most urgent level first
+        merged.priority = keep.priority.min(dropped.priority);
+
+        merged.description = match (&keep.description, &dropped.description) {
+            (Some(kept), Some(other)) if kept != other => Some(format!("{}\n\n{}", kept, other)),
+            (None, Some(other)) => Some(other.clone()),
+            _ => keep.description.clone(),
+        };
+
+        if prefer == MergePreference::Drop {
+            merged.status = dropped.status;
+            merged.assigned_to = dropped.assigned_to.clone();
+        }
+
+        for related in &dropped.related_features {
+            if related != keep_id && !merged.related_features.contains(related) {
+                merged.related_features.push(related.clone());
+            }
+        }
+        merged.related_features.retain(|related| related != drop_id);
+        merged.updated_at = Utc::now();
+
+        let mut operations = vec![UndoOperation::FeatureUpdated { before: keep }];
+        self.save_feature(&merged)?;
+        self.features.insert(merged.id.clone(), merged.clone());
+
+        // Re-point references from other features
+        let referencing: Vec<String> = self
+            .features
+            .values()
+            .filter(|feature| feature.id != keep_id && feature.id != drop_id)
+            .filter(|feature| feature.related_features.iter().any(|r| r == drop_id))
+            .map(|feature| feature.id.clone())
+            .collect();
+        for id in &referencing {
+            let feature = self
+                .features
+                .get_mut(id)
+                .expect("referencing feature exists");
+            operations.push(UndoOperation::FeatureUpdated {
+                before: feature.clone(),
+            });
+            feature
+                .related_features
+                .retain(|related| related != drop_id);
+            if !feature.related_features.iter().any(|r| r == keep_id) {
+                feature.related_features.push(keep_id.to_string());
+            }
+            feature.updated_at = Utc::now();
+            let feature_clone = feature.clone();
+            self.save_feature(&feature_clone)?;
+        }
+
+        // Archive the duplicate outside the directory features are loaded from
+        let features_path = fargin_dir(&self.project_path).join("features");
+        let archive_dir = features_path.join("archive");
+        fs::create_dir_all(&archive_dir)?;
+        let archived_path = archive_dir.join(format!("{}.md", drop_id));
+        fs::write(&archived_path, Self::feature_to_file(&dropped)?)?;
+
+        let feature_path = features_path.join(format!("{}.md", drop_id));
+        if feature_path.exists() {
+            fs::remove_file(feature_path)?;
+        }
+        self.features.remove(drop_id);
+        operations.push(UndoOperation::FeatureRemoved {
+            feature: dropped.clone(),
+        });
+
+        // One entry so a single undo reverts the whole merge
+        UndoJournal::new(&self.project_path).record(UndoOperation::Batch {
+            summary: format!("Merged feature {} into {}", drop_id, keep_id),
+            operations,
+        })?;
+
+        Ok(MergeSummary {
+            kept: merged,
+            dropped,
+            added_tags,
+            added_criteria,
+            repointed: referencing,
+            archived_path,
+        })
+    }
+
     /// Generate implementation suggestions for a feature
     pub fn generate_feature_suggestions(
         &self,
@@ -351,14 +2088,45 @@ impl FeatureManager {
     ) -> Vec<FeatureSuggestion> {
         // More intelligent suggestion generation
         let base_suggestions = match suggestion_type {
-            Some(st) => self.generate_specific_suggestions(feature, st),
+            Some(st) => {
+                let requested = format!("{:?} suggestions were requested explicitly", st);
+                let mut suggestions = self.generate_specific_suggestions(feature, st);
+                for suggestion in &mut suggestions {
+                    suggestion.reasons.insert(0, requested.clone());
+                }
+                suggestions
+            }
             None => self.generate_comprehensive_suggestions(feature),
         };
+        let base_suggestions = Self::weight_by_complexity(feature, base_suggestions);
 
         // Apply verbosity filtering
         self.filter_suggestions_by_verbosity(base_suggestions, verbosity)
     }
 
+    /// Scale each suggestion's effort estimate by the feature's complexity
+    ///
+    /// Generators estimate effort for a feature of average complexity (5);
+    /// features without a complexity are left as estimated.
+    fn weight_by_complexity(
+        feature: &Feature,
+        mut suggestions: Vec<FeatureSuggestion>,
+    ) -> Vec<FeatureSuggestion> {
+        let Some(complexity) = feature.complexity else {
+            return suggestions;
+        };
+
+        for suggestion in &mut suggestions {
+            let weighted = (f64::from(suggestion.complexity) * f64::from(complexity) / 5.0).round();
+            suggestion.complexity = weighted.clamp(1.0, 10.0) as u8;
+            suggestion.reasons.push(format!(
+                "Effort was scaled for a feature of complexity {}",
+                complexity
+            ));
+        }
+        suggestions
+    }
+
     fn generate_specific_suggestions(
         &self,
         feature: &Feature,
@@ -387,7 +2155,14 @@ impl FeatureManager {
 
         // Add context-specific suggestions based on feature attributes
         if feature.priority == Priority::High {
-            suggestions.extend(self.generate_optimization_suggestions(feature));
+            let mut optimization = self.generate_optimization_suggestions(feature);
+            for suggestion in &mut optimization {
+                suggestion.reasons.insert(
+                    0,
+                    "Priority is High, so optimization was included".to_string(),
+                );
+            }
+            suggestions.extend(optimization);
         }
 
         suggestions
@@ -409,6 +2184,7 @@ impl FeatureManager {
                 "Create detailed design document".to_string(),
                 "Break down into smaller tasks".to_string(),
             ],
+            reasons: vec!["Every feature needs an implementation approach".to_string()],
         }]
     }
 
@@ -425,6 +2201,7 @@ impl FeatureManager {
                 "Define unit test cases".to_string(),
                 "Create integration test plan".to_string(),
             ],
+            reasons: vec!["Every feature needs test coverage".to_string()],
         }]
     }
 
@@ -441,72 +2218,557 @@ impl FeatureManager {
                 "Create user guide".to_string(),
                 "Write technical documentation".to_string(),
             ],
+            reasons: vec!["Every feature needs user and technical documentation".to_string()],
         }]
     }
 
-    // Add placeholder methods for other suggestion types
-    fn generate_optimization_suggestions(&self, _feature: &Feature) -> Vec<FeatureSuggestion> {
-        vec![]
+    fn generate_optimization_suggestions(&self, feature: &Feature) -> Vec<FeatureSuggestion> {
+        let mut next_steps = vec![
+            "Identify the hot paths this feature adds".to_string(),
+            "Record a baseline before optimizing".to_string(),
+        ];
+        let mut reasons = vec!["Optimization starts from a measured baseline".to_string()];
+        if feature.complexity.is_some_and(|complexity| complexity >= 7) {
+            next_steps.push("Split expensive steps so they can be cached or deferred".to_string());
+            reasons.push(
+                "Complexity is 7 or more, so caching and deferral were suggested".to_string(),
+            );
+        }
+
+        vec![FeatureSuggestion {
+            id: format!("{}-opt-1", feature.id),
+            suggestion_type: SuggestionType::Optimization,
+            content: format!("Optimize the critical paths of {}", feature.name),
+            confidence: 0.6,
+            complexity: 5,
+            impact: SuggestionImpact::Medium,
+            tags: vec!["efficiency".to_string()],
+            next_steps,
+            reasons,
+        }]
     }
 
-    fn generate_architecture_suggestions(&self, _feature: &Feature) -> Vec<FeatureSuggestion> {
-        vec![]
+    fn generate_architecture_suggestions(&self, feature: &Feature) -> Vec<FeatureSuggestion> {
+        let mut next_steps = vec![
+            "Decide which module owns the feature".to_string(),
+            "Define the public interface before implementing".to_string(),
+        ];
+        let mut reasons = vec!["New features should have a clear owner and interface".to_string()];
+        if !feature.related_features.is_empty() {
+            next_steps.push(format!(
+                "Agree on contracts with related features: {}",
+                feature.related_features.join(", ")
+            ));
+            reasons.push("The feature has related features to integrate with".to_string());
+        }
+        if has_tag(feature, &["api", "backend", "service"]) {
+            next_steps.push("Version the API surface from the start".to_string());
+            reasons.push("Tags mark the feature as an API or service".to_string());
+        }
+
+        vec![FeatureSuggestion {
+            id: format!("{}-arch-1", feature.id),
+            suggestion_type: SuggestionType::Architecture,
+            content: format!("Architectural boundaries for {}", feature.name),
+            confidence: 0.7,
+            complexity: 6,
+            impact: SuggestionImpact::High,
+            tags: vec!["architecture".to_string(), "design".to_string()],
+            next_steps,
+            reasons,
+        }]
     }
 
-    fn generate_performance_suggestions(&self, _feature: &Feature) -> Vec<FeatureSuggestion> {
-        vec![]
+    fn generate_performance_suggestions(&self, feature: &Feature) -> Vec<FeatureSuggestion> {
+        let mut next_steps = vec![
+            "Add a benchmark for the main operation".to_string(),
+            "Set a latency or throughput budget".to_string(),
+        ];
+        let mut reasons = vec!["Performance regressions are cheapest to catch early".to_string()];
+        if has_tag(feature, &["database", "db", "storage", "search"]) {
+            next_steps.push("Check query plans and add indexes where needed".to_string());
+            reasons.push("Tags mention data storage or search".to_string());
+        }
+        let impact = if matches!(feature.priority, Priority::Critical | Priority::High) {
+            reasons.push(format!(
+                "Priority is {:?}, so impact was raised",
+                feature.priority
+            ));
+            SuggestionImpact::High
+        } else {
+            SuggestionImpact::Medium
+        };
+
+        vec![FeatureSuggestion {
+            id: format!("{}-perf-1", feature.id),
+            suggestion_type: SuggestionType::Performance,
+            content: format!("Performance budget and benchmarks for {}", feature.name),
+            confidence: 0.6,
+            complexity: 5,
+            impact,
+            tags: vec!["performance".to_string(), "benchmarks".to_string()],
+            next_steps,
+            reasons,
+        }]
     }
 
-    fn generate_security_suggestions(&self, _feature: &Feature) -> Vec<FeatureSuggestion> {
-        vec![]
+    fn generate_security_suggestions(&self, feature: &Feature) -> Vec<FeatureSuggestion> {
+        let mut next_steps = vec![
+            "Validate and bound every external input".to_string(),
+            "List the data the feature reads and writes".to_string(),
+        ];
+        let mut reasons = vec!["Every feature handles some external input".to_string()];
+        let mut impact = SuggestionImpact::Medium;
+        if has_tag(feature, &["auth", "authentication", "login", "security"]) {
+            next_steps
+                .push("Require authentication and check authorization per action".to_string());
+            next_steps.push("Rate-limit credential checks and log failures".to_string());
+            reasons.push("Tags mark the feature as security-sensitive".to_string());
+            impact = SuggestionImpact::Critical;
+        }
+        if has_tag(feature, &["api", "web", "http"]) {
+            next_steps.push("Review the endpoint against the OWASP Top 10".to_string());
+            reasons.push("Tags mark the feature as network-facing".to_string());
+        }
+
+        vec![FeatureSuggestion {
+            id: format!("{}-sec-1", feature.id),
+            suggestion_type: SuggestionType::Security,
+            content: format!("Threat model and input validation for {}", feature.name),
+            confidence: 0.8,
+            complexity: 5,
+            impact,
+            tags: vec!["security".to_string()],
+            next_steps,
+            reasons,
+        }]
     }
 
-    fn generate_refactoring_suggestions(&self, _feature: &Feature) -> Vec<FeatureSuggestion> {
-        vec![]
+    fn generate_refactoring_suggestions(&self, feature: &Feature) -> Vec<FeatureSuggestion> {
+        let mut next_steps = vec!["Extract shared logic instead of copying it".to_string()];
+        let mut reasons = vec!["New code tends to duplicate what already exists".to_string()];
+        if feature.complexity.is_some_and(|complexity| complexity >= 7) {
+            next_steps.push("Refactor the touched modules before adding the feature".to_string());
+            reasons.push(
+                "Complexity is 7 or more, so preparatory refactoring was suggested".to_string(),
+            );
+        }
+        if feature.status == FeatureStatus::Implemented {
+            next_steps.push("Remove flags and dead code left from the rollout".to_string());
+            reasons.push("The feature is implemented, so cleanup applies".to_string());
+        }
+
+        vec![FeatureSuggestion {
+            id: format!("{}-refactor-1", feature.id),
+            suggestion_type: SuggestionType::Refactoring,
+            content: format!("Keep the code around {} maintainable", feature.name),
+            confidence: 0.5,
+            complexity: 4,
+            impact: SuggestionImpact::Medium,
+            tags: vec!["maintainability".to_string()],
+            next_steps,
+            reasons,
+        }]
     }
 
-    fn generate_ux_suggestions(&self, _feature: &Feature) -> Vec<FeatureSuggestion> {
-        vec![]
+    fn generate_ux_suggestions(&self, feature: &Feature) -> Vec<FeatureSuggestion> {
+        let mut next_steps = vec![
+            "Write down the user's goal in one sentence".to_string(),
+            "Make error messages say how to recover".to_string(),
+        ];
+        let mut reasons = vec!["Every feature is used by someone".to_string()];
+        if has_tag(feature, &["ui", "frontend", "ux", "web"]) {
+            next_steps.push("Check keyboard navigation and contrast".to_string());
+            next_steps.push("Test the flow with a user before finishing".to_string());
+            reasons.push("Tags mark the feature as user-facing".to_string());
+        }
+        if has_tag(feature, &["cli"]) {
+            next_steps.push("Provide --help examples and consistent flag names".to_string());
+            reasons.push("Tags mark the feature as a command-line interface".to_string());
+        }
+
+        vec![FeatureSuggestion {
+            id: format!("{}-ux-1", feature.id),
+            suggestion_type: SuggestionType::UserExperience,
+            content: format!("User experience review for {}", feature.name),
+            confidence: 0.6,
+            complexity: 4,
+            impact: SuggestionImpact::Medium,
+            tags: vec!["ux".to_string(), "usability".to_string()],
+            next_steps,
+            reasons,
+        }]
     }
 
+    /// Shape suggestions for the requested verbosity
+    ///
+    /// - `low`: a curated subset of at most three suggestions with High or
+    ///   Critical impact and confidence of at least 0.7; if none qualify, the
+    ///   single most impactful suggestion is kept
+    /// - `normal` (default): every suggestion
+    /// - `high`: every suggestion, with confidence, complexity and impact
+    ///   detail appended to its content
     fn filter_suggestions_by_verbosity(
         &self,
-        suggestions: Vec<FeatureSuggestion>,
+        mut suggestions: Vec<FeatureSuggestion>,
         verbosity: &str,
     ) -> Vec<FeatureSuggestion> {
-        match verbosity {
-            "low" => suggestions
-                .into_iter()
-                .filter(|s| s.confidence < 0.5)
-                .collect(),
-            "high" => suggestions
-                .into_iter()
-                .filter(|s| s.confidence > 0.7)
-                .collect(),
-            _ => suggestions, // Normal/default verbosity
-        }
+        const LOW_MIN_CONFIDENCE: f32 = 0.7;
+        const LOW_MAX_SUGGESTIONS: usize = 3;
+
+        let rule = match verbosity {
+            "low" => {
+                // Most impactful and most confident first
+                suggestions.sort_by(|a, b| {
+                    b.impact
+                        .cmp(&a.impact)
+                        .then_with(|| b.confidence.total_cmp(&a.confidence))
+                });
+                let qualifies = |s: &FeatureSuggestion| {
+                    s.impact >= SuggestionImpact::High && s.confidence >= LOW_MIN_CONFIDENCE
+                };
+                if suggestions.iter().any(qualifies) {
+                    suggestions.retain(qualifies);
+                    suggestions.truncate(LOW_MAX_SUGGESTIONS);
+                } else {
+                    suggestions.truncate(1);
+                }
+                "low keeps up to 3 high-impact suggestions with confidence of at least 0.7"
+            }
+            "high" => {
+                for suggestion in &mut suggestions {
+                    suggestion.content.push_str(&format!(
+                        "\n\nConfidence: {:.2} | Complexity: {}/10 | Impact: {:?} | Tags: {}",
+                        suggestion.confidence,
+                        suggestion.complexity,
+                        suggestion.impact,
+                        suggestion.tags.join(", ")
+                    ));
+                }
+                "high keeps every suggestion and adds detail"
+            }
+            _ => "normal keeps every suggestion", // Normal/default verbosity
+        };
+
+        suggestions
+            .into_iter()
+            .map(|mut suggestion| {
+                suggestion.reasons.push(format!(
+                    "Kept by verbosity filter ({}; confidence {:.2})",
+                    rule, suggestion.confidence
+                ));
+                suggestion
+            })
+            .collect()
     }
 }
 
-/// Struct for feature update requests
-#[derive(Default)]
-pub struct FeatureUpdateRequest {
-    pub description: Option<String>,
-    pub status: Option<FeatureStatus>,
-    pub tags: Option<Vec<String>>,
-    pub priority: Option<Priority>,
-    pub assigned_to: Option<String>,
-    pub complexity: Option<u8>,
-    pub related_features: Option<Vec<String>>,
-    pub acceptance_criteria: Option<Vec<String>>,
+/// Where to place a feature relative to another when ranking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankPlacement {
+    Above,
+    Below,
 }
 
-/// Types of feature suggestions
-#[derive(Debug, Serialize, Deserialize, Clone, ValueEnum)]
-pub enum SuggestionType {
-    Implementation,
-    Testing,
-    Optimization,
+/// One item of a markdown checklist
+#[derive(Debug, PartialEq)]
+struct ChecklistItem {
+    name: String,
+    checked: bool,
+    tags: Vec<String>,
+}
+
+/// Parse a `- [ ] text #tag` line; anything else yields `None`
+fn parse_checklist_item(line: &str) -> Option<ChecklistItem> {
+    let rest = line.trim_start();
+    let rest = rest
+        .strip_prefix("- ")
+        .or_else(|| rest.strip_prefix("* "))
+        .or_else(|| rest.strip_prefix("+ "))?
+        .trim_start();
+    let (checked, text) = if let Some(text) = rest.strip_prefix("[ ]") {
+        (false, text)
+    } else if let Some(text) = rest
+        .strip_prefix("[x]")
+        .or_else(|| rest.strip_prefix("[X]"))
+    {
+        (true, text)
+    } else {
+        return None;
+    };
+
+    let mut words: Vec<&str> = text.split_whitespace().collect();
+    let mut tags = Vec::new();
+    while let Some(tag) = words
+        .last()
+        .and_then(|word| word.strip_prefix('#'))
+        .filter(|tag| !tag.is_empty())
+    {
+        tags.insert(0, tag.to_string());
+        words.pop();
+    }
+
+    if words.is_empty() {
+        return None;
+    }
+
+    Some(ChecklistItem {
+        name: words.join(" "),
+        checked,
+        tags,
+    })
+}
+
+/// Whether a feature carries any of the given tags (case-insensitive)
+fn has_tag(feature: &Feature, tags: &[&str]) -> bool {
+    feature
+        .tags
+        .iter()
+        .any(|tag| tags.iter().any(|wanted| tag.eq_ignore_ascii_case(wanted)))
+}
+
+/// Sort a feature listing in place
+///
+/// Ties are broken by ID, so the order is the same on every run.
+pub fn sort_features(features: &mut [&Feature], sort: FeatureSort) {
+    match sort {
+        FeatureSort::Created => features.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.id.cmp(&b.id))
+        }),
+        FeatureSort::Updated => features.sort_by(|a, b| {
+            b.updated_at
+                .cmp(&a.updated_at)
+                .then_with(|| a.id.cmp(&b.id))
+        }),
+        FeatureSort::Priority => {
+            features.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.id.cmp(&b.id)))
+        }
+        FeatureSort::Name => features.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then_with(|| a.id.cmp(&b.id))
+        }),
+        FeatureSort::Status => {
+            features.sort_by(|a, b| a.status.cmp(&b.status).then_with(|| a.id.cmp(&b.id)))
+        }
+        FeatureSort::Rank => features.sort_by(|a, b| {
+            a.status
+                .cmp(&b.status)
+                .then_with(|| match (a.rank, b.rank) {
+                    (Some(a), Some(b)) => a.total_cmp(&b),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                })
+                .then_with(|| a.id.cmp(&b.id))
+        }),
+    }
+}
+
+/// Render features as a GitHub-flavored markdown table
+pub fn features_markdown_table(features: &[&Feature]) -> String {
+    let mut table = String::from(
+        "| ID | Name | Status | Priority | Assignee | Tags |\n\
+         |----|------|--------|----------|----------|------|\n",
+    );
+
+    for feature in features {
+        table.push_str(&format!(
+            "| {} | {} | {} | {:?} | {} | {} |\n",
+            escape_table_cell(&feature.id),
+            escape_table_cell(&feature.name),
+            feature.status,
+            feature.priority,
+            escape_table_cell(feature.assigned_to.as_deref().unwrap_or("Unassigned")),
+            escape_table_cell(&feature.tags.join(", "))
+        ));
+    }
+
+    table
+}
+
+/// Render features as an HTML table
+pub fn features_html_table(features: &[&Feature]) -> String {
+    let mut table = String::from(
+        "<table>\n<tr><th>ID</th><th>Name</th><th>Status</th><th>Priority</th>\
+         <th>Assignee</th><th>Tags</th></tr>\n",
+    );
+
+    for feature in features {
+        table.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:?}</td><td>{}</td><td>{}</td></tr>\n",
+            html_escape(&feature.id),
+            html_escape(&feature.name),
+            feature.status,
+            feature.priority,
+            html_escape(feature.assigned_to.as_deref().unwrap_or("Unassigned")),
+            html_escape(&feature.tags.join(", "))
+        ));
+    }
+
+    table.push_str("</table>");
+    table
+}
+
+/// Escape a value so it can't break out of a markdown table cell
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Complexity is an effort estimate on a 1-10 scale
+fn validate_complexity(complexity: u8) -> Result<()> {
+    if !(1..=10).contains(&complexity) {
+        return Err(FarginError::validation(format!(
+            "Complexity must be between 1 and 10, got {}",
+            complexity
+        )));
+    }
+    Ok(())
+}
+
+/// IDs become file names, so refuse anything that could escape the features directory
+fn validate_feature_id(id: &str) -> Result<()> {
+    if id.is_empty() || id.starts_with('.') || id.contains(['/', '\\']) {
+        return Err(FarginError::validation(format!(
+            "Invalid feature ID: {:?}",
+            id
+        )));
+    }
+    Ok(())
+}
+
+/// Mermaid node ID for a feature; Mermaid only accepts a limited character set
+fn mermaid_node_id(id: &str) -> String {
+    let safe: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("f_{}", safe)
+}
+
+/// Mermaid class name used to style a status
+fn mermaid_status_class(status: FeatureStatus) -> String {
+    status.to_string().to_lowercase()
+}
+
+/// Build a stable anchor slug for a feature ID
+///
+/// Anchors are derived from the ID rather than the name, so two features
+/// sharing a name still get distinct links.
+fn feature_anchor(id: &str) -> String {
+    let slug = id
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+
+    format!("feature-{}", slug)
+}
+
+/// Parse a `--due` value: an RFC 3339 timestamp or a `YYYY-MM-DD` date
+///
+/// A bare date means the end of that day (UTC), so work due today is not
+/// overdue until the day is over.
+pub fn parse_due_date(value: &str) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(|time| time.and_utc())
+        .ok_or_else(|| {
+            format!(
+                "Invalid due date {:?}: expected YYYY-MM-DD or RFC 3339",
+                value
+            )
+        })
+}
+
+/// Criteria selecting a set of features; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct FeatureFilter {
+    /// Tags to look for; how many must match is set by `tag_match`
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    pub status: Option<FeatureStatus>,
+    pub priority: Option<Priority>,
+    pub assigned_to: Option<String>,
+}
+
+/// How a filter with several tags matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum TagMatch {
+    /// Features with at least one of the tags
+    #[default]
+    Any,
+    /// Features with every one of the tags
+    All,
+}
+
+impl FeatureFilter {
+    /// Whether a feature meets every set criterion
+    pub fn matches(&self, feature: &Feature) -> bool {
+        let tags_match = self.tags.is_empty()
+            || match self.tag_match {
+                TagMatch::Any => self.tags.iter().any(|t| feature.tags.contains(t)),
+                TagMatch::All => self.tags.iter().all(|t| feature.tags.contains(t)),
+            };
+
+        tags_match
+            && self.status.is_none_or(|s| feature.status == s)
+            && self.priority.is_none_or(|p| feature.priority == p)
+            && self.assigned_to.as_deref().is_none_or(|a| {
+                feature
+                    .assigned_to
+                    .as_deref()
+                    .is_some_and(|assignee| assignee.eq_ignore_ascii_case(a))
+            })
+    }
+}
+
+/// Fields for a new feature; only the name is required
+#[derive(Default, Clone)]
+pub struct NewFeature {
+    pub name: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub priority: Option<Priority>,
+    pub assigned_to: Option<String>,
+    pub acceptance_criteria: Vec<String>,
+    pub related_features: Vec<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub complexity: Option<u8>,
+}
+
+/// Struct for feature update requests
+#[derive(Default, Clone)]
+pub struct FeatureUpdateRequest {
+    pub description: Option<String>,
+    pub status: Option<FeatureStatus>,
+    pub tags: Option<Vec<String>>,
+    pub priority: Option<Priority>,
+    pub assigned_to: Option<String>,
+    pub complexity: Option<u8>,
+    pub related_features: Option<Vec<String>>,
+    pub acceptance_criteria: Option<Vec<String>>,
+    pub rank: Option<f64>,
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Allow status transitions `FeatureStatus::can_transition_to` rejects
+    pub force: bool,
+}
+
+/// Types of feature suggestions
+#[derive(Debug, Serialize, Deserialize, Clone, ValueEnum)]
+pub enum SuggestionType {
+    Implementation,
+    Testing,
+    Optimization,
     Documentation,
     Architecture,
     Performance,
@@ -541,10 +2803,13 @@ pub struct FeatureSuggestion {
 
     /// Recommended next steps
     pub next_steps: Vec<String>,
+
+    /// Conditions that caused the suggestion to be generated and kept
+    pub reasons: Vec<String>,
 }
 
 /// Impact level of a suggestion
-#[derive(Debug, Serialize, Deserialize, Clone, ValueEnum)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum SuggestionImpact {
     Low,
     Medium,
@@ -555,6 +2820,7 @@ pub enum SuggestionImpact {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anyhow::Result;
     use tempfile::tempdir;
 
     #[test]
@@ -562,13 +2828,14 @@ mod tests {
         let temp_dir = tempdir()?;
         let mut manager = FeatureManager::new(temp_dir.path())?;
 
-        let feature_id = manager.add_feature(
-            "User Authentication".to_string(),
-            Some("Implement secure user login".to_string()),
-            Some(vec!["security".to_string()]),
-            Some(Priority::High),
-            Some("dev-team".to_string()),
-        )?;
+        let feature_id = manager.add_feature(NewFeature {
+            name: "User Authentication".to_string(),
+            description: Some("Implement secure user login".to_string()),
+            tags: vec!["security".to_string()],
+            priority: Some(Priority::High),
+            assigned_to: Some("dev-team".to_string()),
+            ..Default::default()
+        })?;
 
         let feature = manager
             .get_feature(&feature_id)
@@ -586,13 +2853,11 @@ mod tests {
         let temp_dir = tempdir()?;
         let mut manager = FeatureManager::new(temp_dir.path())?;
 
-        let feature_id = manager.add_feature(
-            "Payment Integration".to_string(),
-            Some("Add payment gateway".to_string()),
-            None,
-            None,
-            None,
-        )?;
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Payment Integration".to_string(),
+            description: Some("Add payment gateway".to_string()),
+            ..Default::default()
+        })?;
 
         manager.update_feature(
             &feature_id,
@@ -612,4 +2877,1600 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_priority_bump_and_lower() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Rate Limiting".to_string(),
+            ..Default::default()
+        })?;
+
+        assert_eq!(
+            manager.raise_priority(&feature_id)?,
+            (Priority::Medium, Priority::High)
+        );
+        assert_eq!(
+            manager.raise_priority(&feature_id)?,
+            (Priority::High, Priority::Critical)
+        );
+        assert_eq!(
+            manager.raise_priority(&feature_id)?,
+            (Priority::Critical, Priority::Critical)
+        );
+
+        assert_eq!(Priority::Low.lowered(), Priority::Low);
+        assert_eq!(
+            manager.lower_priority(&feature_id)?,
+            (Priority::Critical, Priority::High)
+        );
+        assert!(manager.raise_priority("missing").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_implementation_order() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let api = manager.add_feature(NewFeature {
+            name: "API".to_string(),
+            ..Default::default()
+        })?;
+        let storage = manager.add_feature(NewFeature {
+            name: "Storage".to_string(),
+            ..Default::default()
+        })?;
+        let schema = manager.add_feature(NewFeature {
+            name: "Schema".to_string(),
+            ..Default::default()
+        })?;
+
+        manager.features.get_mut(&api).unwrap().related_features = vec![storage.clone()];
+        manager.features.get_mut(&storage).unwrap().related_features = vec![schema.clone()];
+        manager.update_feature(
+            &schema,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Implemented),
+                ..Default::default()
+            },
+        )?;
+
+        let order: Vec<&str> = manager
+            .implementation_order(None)?
+            .iter()
+            .map(|feature| feature.name.as_str())
+            .collect();
+        assert_eq!(order, vec!["Storage", "API"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_implementation_order_reports_cycle() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let first = manager.add_feature(NewFeature {
+            name: "First".to_string(),
+            ..Default::default()
+        })?;
+        let second = manager.add_feature(NewFeature {
+            name: "Second".to_string(),
+            ..Default::default()
+        })?;
+
+        manager.features.get_mut(&first).unwrap().related_features = vec![second.clone()];
+        manager.features.get_mut(&second).unwrap().related_features = vec![first.clone()];
+
+        let error = manager.implementation_order(None).unwrap_err();
+        assert!(error.to_string().contains(&first));
+        assert!(error.to_string().contains(&second));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_feature() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let first = manager.add_feature(NewFeature {
+            name: "Alpha".to_string(),
+            ..Default::default()
+        })?;
+        let second = manager.add_feature(NewFeature {
+            name: "Beta".to_string(),
+            ..Default::default()
+        })?;
+        let third = manager.add_feature(NewFeature {
+            name: "Gamma".to_string(),
+            ..Default::default()
+        })?;
+
+        assert_eq!(
+            manager.rank_feature(&third, &first, RankPlacement::Above)?,
+            0.0
+        );
+        assert_eq!(
+            manager.rank_feature(&second, &third, RankPlacement::Below)?,
+            0.5
+        );
+        assert!(manager
+            .rank_feature(&first, &first, RankPlacement::Above)
+            .is_err());
+
+        let mut features = manager.list_features(None, None, None, None);
+        sort_features(&mut features, FeatureSort::Rank);
+        let names: Vec<&str> = features.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["Gamma", "Beta", "Alpha"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_features_markdown_table_escapes_pipes() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Import | Export".to_string(),
+            tags: vec!["io|fs".to_string(), "cli".to_string()],
+            priority: Some(Priority::High),
+            assigned_to: Some("dana".to_string()),
+            ..Default::default()
+        })?;
+
+        let features = manager.list_features(None, None, None, None);
+        let table = features_markdown_table(&features);
+        let rows: Vec<&str> = table.lines().collect();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[2],
+            format!(
+                "| {} | Import \\| Export | Proposed | High | dana | io\\|fs, cli |",
+                feature_id
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_features_html_table_escapes_markup() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        manager.add_feature(NewFeature {
+            name: "<script> & friends".to_string(),
+            tags: vec!["a<b".to_string()],
+            ..Default::default()
+        })?;
+
+        let features = manager.list_features(None, None, None, None);
+        let table = features_html_table(&features);
+
+        assert!(table.contains("<td>&lt;script&gt; &amp; friends</td>"));
+        assert!(table.contains("<td>a&lt;b</td>"));
+        assert!(!table.contains("<script>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_suggestion_reasons() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Checkout".to_string(),
+            priority: Some(Priority::High),
+            ..Default::default()
+        })?;
+        let feature = manager.get_feature(&feature_id).unwrap();
+
+        let suggestions = manager.generate_feature_suggestions(feature, None, "normal");
+        assert!(!suggestions.is_empty());
+        for suggestion in &suggestions {
+            assert!(suggestion
+                .reasons
+                .iter()
+                .any(|reason| reason.contains("normal keeps every suggestion")));
+        }
+
+        let suggestions =
+            manager.generate_feature_suggestions(feature, Some(SuggestionType::Testing), "normal");
+        assert_eq!(
+            suggestions[0].reasons[0],
+            "Testing suggestions were requested explicitly"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_by_status_and_priority() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let login = manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Logout".to_string(),
+            priority: Some(Priority::Low),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Signup".to_string(),
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &login,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Implemented),
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!(
+            manager.count_by(CountField::Status),
+            vec![("Proposed".to_string(), 2), ("Implemented".to_string(), 1)]
+        );
+        assert_eq!(
+            manager.count_by(CountField::Priority),
+            vec![("Medium".to_string(), 2), ("Low".to_string(), 1)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_by_tag_and_assignee() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            tags: vec!["auth".to_string(), "ui".to_string()],
+            assigned_to: Some("sam".to_string()),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Tokens".to_string(),
+            tags: vec!["auth".to_string()],
+            ..Default::default()
+        })?;
+
+        assert_eq!(
+            manager.count_by(CountField::Tag),
+            vec![("auth".to_string(), 2), ("ui".to_string(), 1)]
+        );
+        assert_eq!(
+            manager.count_by(CountField::Assignee),
+            vec![("Unassigned".to_string(), 1), ("sam".to_string(), 1)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_markdown() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let done_id = manager.add_feature(NewFeature {
+            name: "Search Index".to_string(),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Dark Mode".to_string(),
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &done_id,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Implemented),
+                ..Default::default()
+            },
+        )?;
+
+        let spec = manager.export_markdown(None, Some(FeatureStatus::Implemented), None);
+        let anchor = feature_anchor(&done_id);
+
+        assert!(spec.contains(&format!("- [Search Index](#{}) (Implemented)", anchor)));
+        assert!(spec.contains(&format!("<a id=\"{}\"></a>", anchor)));
+        assert!(spec.contains("# Feature: Search Index"));
+        assert!(!spec.contains("Dark Mode"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_features() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let keep_id = manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            description: Some("Password login".to_string()),
+            tags: vec!["auth".to_string()],
+            priority: Some(Priority::Low),
+            assigned_to: Some("sam".to_string()),
+            ..Default::default()
+        })?;
+        let drop_id = manager.add_feature(NewFeature {
+            name: "Sign In".to_string(),
+            description: Some("OAuth login".to_string()),
+            tags: vec!["auth".to_string(), "ui".to_string()],
+            priority: Some(Priority::High),
+            assigned_to: Some("alex".to_string()),
+            ..Default::default()
+        })?;
+        let other_id = manager.add_feature(NewFeature {
+            name: "Profile".to_string(),
+            ..Default::default()
+        })?;
+        manager
+            .features
+            .get_mut(&other_id)
+            .unwrap()
+            .related_features = vec![drop_id.clone()];
+
+        let summary = manager.merge_features(&keep_id, &drop_id, MergePreference::Keep)?;
+
+        assert_eq!(summary.added_tags, vec!["ui".to_string()]);
+        assert_eq!(summary.repointed, vec![other_id.clone()]);
+        assert!(summary.archived_path.exists());
+
+        let kept = manager
+            .get_feature(&keep_id)
+            .expect("Kept feature should exist");
+        assert_eq!(kept.priority, Priority::High);
+        assert_eq!(kept.assigned_to.as_deref(), Some("sam"));
+        assert_eq!(
+            kept.description.as_deref(),
+            Some("Password login\n\nOAuth login")
+        );
+        assert!(manager.get_feature(&drop_id).is_none());
+        assert_eq!(
+            manager.get_feature(&other_id).unwrap().related_features,
+            vec![keep_id.clone()]
+        );
+
+        // The archived file must not be loaded back as an active feature
+        let reloaded = FeatureManager::new(temp_dir.path())?;
+        assert!(reloaded.get_feature(&drop_id).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_triage_applies_actions() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let first = manager.add_feature(NewFeature {
+            name: "Alpha".to_string(),
+            ..Default::default()
+        })?;
+        let second = manager.add_feature(NewFeature {
+            name: "Beta".to_string(),
+            ..Default::default()
+        })?;
+        let third = manager.add_feature(NewFeature {
+            name: "Gamma".to_string(),
+            ..Default::default()
+        })?;
+
+        let mut input = std::io::Cursor::new("x\nb\nP\nq\n");
+        let mut output = Vec::new();
+        let summary = manager.triage(
+            None,
+            Some(FeatureStatus::Proposed),
+            None,
+            &mut input,
+            &mut output,
+        )?;
+
+        assert_eq!(
+            summary,
+            TriageSummary {
+                updated: 2,
+                skipped: 0,
+                remaining: 1
+            }
+        );
+        assert_eq!(
+            manager.get_feature(&first).unwrap().status,
+            FeatureStatus::Blocked
+        );
+        assert_eq!(
+            manager.get_feature(&second).unwrap().priority,
+            Priority::High
+        );
+        assert_eq!(
+            manager.get_feature(&third).unwrap().status,
+            FeatureStatus::Proposed
+        );
+        assert!(String::from_utf8(output)?.contains("Unknown action: \"x\""));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_feature_round_trip_through_markdown() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Audit Log".to_string(),
+            description: Some("Record every admin action".to_string()),
+            assigned_to: Some("sam".to_string()),
+            ..Default::default()
+        })?;
+        manager
+            .features
+            .get_mut(&feature_id)
+            .unwrap()
+            .acceptance_criteria = vec![
+            "Entries are immutable".to_string(),
+            "Exports to CSV".to_string(),
+        ];
+        manager.update_feature(
+            &feature_id,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::InProgress),
+                priority: Some(Priority::Critical),
+                ..Default::default()
+            },
+        )?;
+
+        let original = manager.get_feature(&feature_id).unwrap().clone();
+        let reloaded = FeatureManager::new(temp_dir.path())?;
+        let feature = reloaded
+            .get_feature(&feature_id)
+            .expect("Feature should be reloaded");
+
+        assert_eq!(feature.name, original.name);
+        assert_eq!(feature.description, original.description);
+        assert_eq!(feature.status, FeatureStatus::InProgress);
+        assert_eq!(feature.priority, Priority::Critical);
+        assert_eq!(feature.assigned_to, original.assigned_to);
+        assert_eq!(feature.created_at, original.created_at);
+        assert_eq!(feature.updated_at, original.updated_at);
+        assert_eq!(feature.acceptance_criteria, original.acceptance_criteria);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tags_survive_reload() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let tagged_id = manager.add_feature(NewFeature {
+            name: "Two Factor".to_string(),
+            tags: vec!["security".to_string(), "auth".to_string()],
+            ..Default::default()
+        })?;
+        let untagged_id = manager.add_feature(NewFeature {
+            name: "Themes".to_string(),
+            ..Default::default()
+        })?;
+
+        let reloaded = FeatureManager::new(temp_dir.path())?;
+        let auth: Vec<&str> = reloaded
+            .list_features(Some("auth"), None, None, None)
+            .into_iter()
+            .map(|feature| feature.id.as_str())
+            .collect();
+
+        assert_eq!(auth, vec![tagged_id.as_str()]);
+        assert_eq!(
+            reloaded.get_feature(&tagged_id).unwrap().tags,
+            vec!["security".to_string(), "auth".to_string()]
+        );
+        assert!(reloaded.get_feature(&untagged_id).unwrap().tags.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_front_matter_round_trip() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Webhooks".to_string(),
+            description: Some("Notify on change".to_string()),
+            tags: vec!["api".to_string()],
+            priority: Some(Priority::High),
+            ..Default::default()
+        })?;
+        manager.features.get_mut(&feature_id).unwrap().complexity = Some(5);
+        manager.update_feature(
+            &feature_id,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Blocked),
+                ..Default::default()
+            },
+        )?;
+
+        let content = fs::read_to_string(
+            temp_dir
+                .path()
+                .join(format!(".fargin/features/{}.md", feature_id)),
+        )?;
+        assert!(content.starts_with("---\n"));
+        assert!(content.contains("status: Blocked"));
+
+        let reloaded = FeatureManager::new(temp_dir.path())?;
+        let feature = reloaded.get_feature(&feature_id).unwrap();
+        assert_eq!(feature.status, FeatureStatus::Blocked);
+        assert_eq!(feature.priority, Priority::High);
+        assert_eq!(feature.complexity, Some(5));
+        assert_eq!(feature.tags, vec!["api".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_front_matter_keeps_description_verbatim() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let description =
+            "First paragraph.\n\n# Feature: Hijacked\n\n## Tags\nnot, tags\n\nLast paragraph.";
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Markdown Notes".to_string(),
+            description: Some(description.to_string()),
+            tags: vec!["docs".to_string()],
+            ..Default::default()
+        })?;
+
+        let reloaded = FeatureManager::new(temp_dir.path())?;
+        let feature = reloaded.get_feature(&feature_id).unwrap();
+        assert_eq!(feature.name, "Markdown Notes");
+        assert_eq!(feature.description.as_deref(), Some(description));
+        assert_eq!(feature.tags, vec!["docs".to_string()]);
+
+        // Files written before the name moved into the front matter
+        let path = temp_dir
+            .path()
+            .join(format!(".fargin/features/{}.md", feature_id));
+        let content = fs::read_to_string(&path)?;
+        assert!(content.contains("\nname: Markdown Notes\n"), "{}", content);
+        let content = content.replace("name: Markdown Notes\n", "");
+        fs::write(&path, content)?;
+        let reloaded = FeatureManager::new(temp_dir.path())?;
+        let feature = reloaded.get_feature(&feature_id).unwrap();
+        assert_eq!(feature.name, "Markdown Notes");
+        assert_eq!(feature.description.as_deref(), Some(description));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unparseable_feature_file_is_skipped() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Search".to_string(),
+            ..Default::default()
+        })?;
+        let broken = temp_dir.path().join(".fargin/features/broken.md");
+        fs::write(&broken, "---\nstatus: [\n---\n\n# Feature: Broken\n")?;
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert_eq!(manager.len(), 1);
+        assert!(manager.get_feature(&feature_id).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_legacy_markdown_without_front_matter() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let features_dir = temp_dir.path().join(".fargin/features");
+        fs::create_dir_all(&features_dir)?;
+        fs::write(
+            features_dir.join("20240101_000000__legacy.md"),
+            "# Feature: Legacy\n\n\
+            ## Details\n\
+            - **ID**: 20240101_000000__legacy\n\
+            - **Status**: Implemented\n\
+            - **Priority**: Low\n\
+            - **Assigned To**: Unassigned\n\n\
+            ## Description\n\
+            Written by an older fargin\n\n\
+            ## Tags\n\
+            legacy, import",
+        )?;
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        let feature = manager
+            .get_feature("20240101_000000__legacy")
+            .expect("Legacy feature should load");
+
+        assert_eq!(feature.name, "Legacy");
+        assert_eq!(feature.status, FeatureStatus::Implemented);
+        assert_eq!(feature.priority, Priority::Low);
+        assert_eq!(
+            feature.description.as_deref(),
+            Some("Written by an older fargin")
+        );
+        assert_eq!(
+            feature.tags,
+            vec!["legacy".to_string(), "import".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_applies_complexity_and_bumps_updated_at() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Caching".to_string(),
+            ..Default::default()
+        })?;
+        let created = manager.get_feature(&feature_id).unwrap().updated_at;
+
+        manager.update_feature(
+            &feature_id,
+            FeatureUpdateRequest {
+                complexity: Some(8),
+                ..Default::default()
+            },
+        )?;
+
+        let feature = manager.get_feature(&feature_id).unwrap();
+        assert_eq!(feature.complexity, Some(8));
+        assert!(feature.updated_at > created);
+
+        Ok(())
+    }
+
+    fn manager_with_dependencies(
+        temp_dir: &Path,
+        dependencies: &[(&str, &[&str])],
+    ) -> Result<FeatureManager> {
+        let mut manager = FeatureManager::new(temp_dir)?;
+        for (name, _) in dependencies {
+            manager.add_feature(NewFeature {
+                name: name.to_string(),
+                ..Default::default()
+            })?;
+        }
+        let id_of = |manager: &FeatureManager, name: &str| {
+            manager
+                .features
+                .values()
+                .find(|feature| feature.name == name)
+                .map(|feature| feature.id.clone())
+                .unwrap()
+        };
+        for (name, related) in dependencies {
+            let id = id_of(&manager, name);
+            let related = related.iter().map(|other| id_of(&manager, other)).collect();
+            manager.update_feature(
+                &id,
+                FeatureUpdateRequest {
+                    related_features: Some(related),
+                    ..Default::default()
+                },
+            )?;
+        }
+        Ok(manager)
+    }
+
+    #[test]
+    fn test_detect_dependency_cycles_acyclic() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let manager = manager_with_dependencies(
+            temp_dir.path(),
+            &[("a", &["b", "c"]), ("b", &["c"]), ("c", &[])],
+        )?;
+
+        assert!(manager.detect_dependency_cycles().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_dependency_cycles_self_loop() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let manager = manager_with_dependencies(temp_dir.path(), &[("a", &["a"])])?;
+
+        let cycles = manager.detect_dependency_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_dependency_cycles_three_nodes() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let manager = manager_with_dependencies(
+            temp_dir.path(),
+            &[("a", &["b"]), ("b", &["c"]), ("c", &["a"]), ("d", &["a"])],
+        )?;
+
+        let cycles = manager.detect_dependency_cycles();
+        assert_eq!(cycles.len(), 1);
+
+        let mut names: Vec<&str> = cycles[0]
+            .iter()
+            .map(|id| manager.get_feature(id).unwrap().name.as_str())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["a", "b", "c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_ranks_by_field() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let described = manager.add_feature(NewFeature {
+            name: "Reports".to_string(),
+            description: Some("Export PAYMENT history".to_string()),
+            ..Default::default()
+        })?;
+        let named = manager.add_feature(NewFeature {
+            name: "Payments".to_string(),
+            ..Default::default()
+        })?;
+        let tagged = manager.add_feature(NewFeature {
+            name: "Checkout".to_string(),
+            tags: vec!["payment".to_string()],
+            acceptance_criteria: vec!["Retries a failed payment".to_string()],
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Themes".to_string(),
+            ..Default::default()
+        })?;
+
+        let ids: Vec<&str> = manager
+            .search("PayMent")
+            .into_iter()
+            .map(|feature| feature.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![named.as_str(), tagged.as_str(), described.as_str()]
+        );
+        assert!(manager.search("nothing matches").is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_features_by_assignee() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let sam = manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            assigned_to: Some("Sam".to_string()),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Search".to_string(),
+            assigned_to: Some("alex".to_string()),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Themes".to_string(),
+            ..Default::default()
+        })?;
+
+        let ids: Vec<&str> = manager
+            .list_features(None, None, None, Some("sam"))
+            .into_iter()
+            .map(|feature| feature.id.as_str())
+            .collect();
+        assert_eq!(ids, vec![sam.as_str()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statistics() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let login = manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            priority: Some(Priority::High),
+            assigned_to: Some("sam".to_string()),
+            ..Default::default()
+        })?;
+        let search = manager.add_feature(NewFeature {
+            name: "Search".to_string(),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Themes".to_string(),
+            priority: Some(Priority::Low),
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &login,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Implemented),
+                complexity: Some(3),
+                ..Default::default()
+            },
+        )?;
+        manager.update_feature(
+            &search,
+            FeatureUpdateRequest {
+                complexity: Some(6),
+                ..Default::default()
+            },
+        )?;
+
+        let stats = manager.statistics();
+
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.unassigned, 2);
+        assert_eq!(
+            stats.by_status,
+            BTreeMap::from([
+                (FeatureStatus::Proposed, 2),
+                (FeatureStatus::Implemented, 1)
+            ])
+        );
+        assert_eq!(
+            stats.by_priority,
+            BTreeMap::from([
+                (Priority::High, 1),
+                (Priority::Medium, 1),
+                (Priority::Low, 1)
+            ])
+        );
+        assert_eq!(stats.average_complexity, Some(4.5));
+        assert!(stats.to_markdown().contains("| Implemented | 1 |"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_every_suggestion_type_has_output() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            tags: vec!["auth".to_string()],
+            ..Default::default()
+        })?;
+        let feature = manager.get_feature(&feature_id).unwrap().clone();
+
+        for suggestion_type in SuggestionType::value_variants() {
+            let suggestions = manager.generate_feature_suggestions(
+                &feature,
+                Some(suggestion_type.clone()),
+                "normal",
+            );
+            assert!(!suggestions.is_empty(), "{:?} is empty", suggestion_type);
+            assert!(suggestions.iter().all(|s| !s.next_steps.is_empty()));
+        }
+
+        let security = manager.generate_feature_suggestions(
+            &feature,
+            Some(SuggestionType::Security),
+            "normal",
+        );
+        assert!(security[0]
+            .next_steps
+            .iter()
+            .any(|step| step.contains("authentication")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_suggestion_verbosity_levels() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Sync".to_string(),
+            ..Default::default()
+        })?;
+        let feature = manager.get_feature(&feature_id).unwrap().clone();
+
+        let low = manager.generate_feature_suggestions(&feature, None, "low");
+        let normal = manager.generate_feature_suggestions(&feature, None, "normal");
+        let high = manager.generate_feature_suggestions(&feature, None, "high");
+
+        assert!(!low.is_empty());
+        assert!(low.len() < normal.len());
+        assert!(low.iter().all(|s| s.impact >= SuggestionImpact::High));
+        assert_eq!(high.len(), normal.len());
+        assert!(high.iter().all(|s| s.content.contains("Confidence:")));
+
+        // Low still returns something when nothing is high-impact
+        let testing =
+            manager.generate_feature_suggestions(&feature, Some(SuggestionType::Testing), "low");
+        assert_eq!(testing.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_export_import_round_trip() -> Result<()> {
+        let source_dir = tempdir()?;
+        let mut source = FeatureManager::new(source_dir.path())?;
+        let auth = source.add_feature(NewFeature {
+            name: "Auth".to_string(),
+            description: Some("Sign users in".to_string()),
+            tags: vec!["security".to_string()],
+            priority: Some(Priority::High),
+            assigned_to: Some("sam".to_string()),
+            acceptance_criteria: vec!["Passwords are hashed".to_string()],
+            ..Default::default()
+        })?;
+        source.add_feature(NewFeature {
+            name: "Profile".to_string(),
+            related_features: vec![auth.clone()],
+            ..Default::default()
+        })?;
+        source.update_feature(
+            &auth,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::InProgress),
+                rank: Some(1.5),
+                ..Default::default()
+            },
+        )?;
+
+        let exported = source.export_json()?;
+
+        let target_dir = tempdir()?;
+        let mut target = FeatureManager::new(target_dir.path())?;
+        let summary = target.import_json(&exported, ConflictPolicy::Skip)?;
+        assert_eq!(summary.imported.len(), 2);
+
+        // Reload from disk so the comparison covers what was actually saved
+        let reloaded = FeatureManager::new(target_dir.path())?;
+        assert_eq!(reloaded.export_json()?, exported);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_import_conflict_policies() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let id = manager.add_feature(NewFeature {
+            name: "Search".to_string(),
+            description: Some("Original".to_string()),
+            ..Default::default()
+        })?;
+
+        let mut incoming = manager.get_feature(&id).unwrap().clone();
+        incoming.description = Some("Imported".to_string());
+        let json = serde_json::to_string(&vec![incoming])?;
+
+        let skipped = manager.import_json(&json, ConflictPolicy::Skip)?;
+        assert_eq!(skipped.skipped, vec![id.clone()]);
+        assert_eq!(
+            manager.get_feature(&id).unwrap().description.as_deref(),
+            Some("Original")
+        );
+
+        let renamed = manager.import_json(&json, ConflictPolicy::Rename)?;
+        let new_id = format!("{}_imported", id);
+        assert_eq!(renamed.renamed, vec![(id.clone(), new_id.clone())]);
+        assert_eq!(
+            manager.get_feature(&new_id).unwrap().description.as_deref(),
+            Some("Imported")
+        );
+
+        manager.import_json(&json, ConflictPolicy::Overwrite)?;
+        assert_eq!(
+            manager.get_feature(&id).unwrap().description.as_deref(),
+            Some("Imported")
+        );
+
+        let escaping = json.replace(&id, "../outside");
+        assert!(manager
+            .import_json(&escaping, ConflictPolicy::Overwrite)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_checklist() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let checklist = "# Launch\n\n\
+            - [ ] Password reset #auth #email\n\
+            - [x] Login page #auth\n\
+            - [ ] Dark mode\n\
+            Not a task\n";
+        let ids = manager.import_checklist(checklist)?;
+        assert_eq!(ids.len(), 3);
+
+        let imported: Vec<&Feature> = ids
+            .iter()
+            .map(|id| manager.get_feature(id).expect("imported feature exists"))
+            .collect();
+
+        assert_eq!(imported[0].name, "Password reset");
+        assert_eq!(imported[0].status, FeatureStatus::Proposed);
+        assert_eq!(imported[0].tags, vec!["auth", "email"]);
+
+        assert_eq!(imported[1].name, "Login page");
+        assert_eq!(imported[1].status, FeatureStatus::Implemented);
+        assert_eq!(imported[1].tags, vec!["auth"]);
+
+        assert_eq!(imported[2].name, "Dark mode");
+        assert_eq!(imported[2].status, FeatureStatus::Proposed);
+        assert!(imported[2].tags.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_changes_are_recorded_in_order() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let id = manager.add_feature(NewFeature {
+            name: "Audit Log".to_string(),
+            ..Default::default()
+        })?;
+
+        for status in [FeatureStatus::InProgress, FeatureStatus::Implemented] {
+            manager.update_feature(
+                &id,
+                FeatureUpdateRequest {
+                    status: Some(status),
+                    ..Default::default()
+                },
+            )?;
+        }
+        // Updates that leave the status alone are not history
+        manager.update_feature(
+            &id,
+            FeatureUpdateRequest {
+                priority: Some(Priority::High),
+                ..Default::default()
+            },
+        )?;
+
+        let history = FeatureManager::new(temp_dir.path())?.history(&id)?;
+        let transitions: Vec<_> = history
+            .iter()
+            .map(|change| (change.from, change.to))
+            .collect();
+        assert_eq!(
+            transitions,
+            vec![
+                (FeatureStatus::Proposed, FeatureStatus::InProgress),
+                (FeatureStatus::InProgress, FeatureStatus::Implemented),
+            ]
+        );
+        assert!(history[0].timestamp <= history[1].timestamp);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mermaid_graph() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let auth = manager.add_feature(NewFeature {
+            name: "Auth".to_string(),
+            ..Default::default()
+        })?;
+        let profile = manager.add_feature(NewFeature {
+            name: "User \"Profile\"".to_string(),
+            related_features: vec![auth.clone(), "missing_feature".to_string()],
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &auth,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Implemented),
+                ..Default::default()
+            },
+        )?;
+
+        let graph = manager.to_mermaid();
+        assert!(graph.starts_with("graph TD\n"));
+        assert!(graph.contains(&format!("    f_{}[\"Auth\"]:::implemented\n", auth)));
+        assert!(graph.contains(&format!(
+            "    f_{}[\"User #quot;Profile#quot;\"]:::proposed\n",
+            profile
+        )));
+        assert!(graph.contains(&format!("    f_{} --> f_{}\n", profile, auth)));
+        assert!(!graph.contains("missing_feature"));
+        assert!(graph.contains("    classDef implemented "));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_keeps_references_resolving() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let auth = manager.add_feature(NewFeature {
+            name: "Auth".to_string(),
+            ..Default::default()
+        })?;
+        let profile = manager.add_feature(NewFeature {
+            name: "Profile".to_string(),
+            related_features: vec![auth.clone()],
+            ..Default::default()
+        })?;
+
+        manager.rename(&auth, "Single Sign-On")?;
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        let reference = &manager.get_feature(&profile).unwrap().related_features[0];
+        let referenced = manager
+            .get_feature(reference)
+            .expect("reference still resolves");
+        assert_eq!(referenced.name, "Single Sign-On");
+        Ok(())
+    }
+
+    #[test]
+    fn test_reassign_id_rewrites_references() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let auth = manager.add_feature(NewFeature {
+            name: "Auth".to_string(),
+            ..Default::default()
+        })?;
+        let profile = manager.add_feature(NewFeature {
+            name: "Profile".to_string(),
+            related_features: vec![auth.clone()],
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &auth,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::InProgress),
+                ..Default::default()
+            },
+        )?;
+
+        let rewritten = manager.reassign_id(&auth, "auth")?;
+        assert_eq!(rewritten, vec![profile.clone()]);
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert!(manager.get_feature(&auth).is_none());
+        assert_eq!(manager.get_feature("auth").unwrap().name, "Auth");
+        assert_eq!(
+            manager.get_feature(&profile).unwrap().related_features,
+            vec!["auth"]
+        );
+        assert_eq!(manager.history("auth")?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_matching_only_touches_matches() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let tags = |tag: &str| vec![tag.to_string()];
+
+        let token_storage = manager.add_feature(NewFeature {
+            name: "Token Storage".to_string(),
+            tags: tags("security"),
+            priority: Some(Priority::Low),
+            ..Default::default()
+        })?;
+        let csrf = manager.add_feature(NewFeature {
+            name: "CSRF Protection".to_string(),
+            tags: tags("security"),
+            ..Default::default()
+        })?;
+        let audit = manager.add_feature(NewFeature {
+            name: "Audit Trail".to_string(),
+            tags: tags("security"),
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &audit,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::InProgress),
+                ..Default::default()
+            },
+        )?;
+        let themes = manager.add_feature(NewFeature {
+            name: "Themes".to_string(),
+            tags: tags("ui"),
+            ..Default::default()
+        })?;
+
+        let changed = manager.update_matching(
+            FeatureFilter {
+                tags: vec!["security".to_string()],
+                status: Some(FeatureStatus::Proposed),
+                ..Default::default()
+            },
+            FeatureUpdateRequest {
+                priority: Some(Priority::High),
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!(changed, 2);
+        let priority = |id: &str| manager.get_feature(id).unwrap().priority;
+        assert_eq!(priority(&token_storage), Priority::High);
+        assert_eq!(priority(&csrf), Priority::High);
+        assert_eq!(priority(&audit), Priority::Medium);
+        assert_eq!(priority(&themes), Priority::Medium);
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_transitions() -> Result<()> {
+        use FeatureStatus::*;
+
+        assert!(Implemented.can_transition_to(Deprecated));
+        assert!(Blocked.can_transition_to(InProgress));
+        assert!(!Deprecated.can_transition_to(Proposed));
+        assert!(!Deprecated.can_transition_to(InProgress));
+        assert!(!Implemented.can_transition_to(Proposed));
+
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let id = manager.add_feature(NewFeature {
+            name: "Legacy Export".to_string(),
+            ..Default::default()
+        })?;
+        let set_status = |status, force| FeatureUpdateRequest {
+            status: Some(status),
+            force,
+            ..Default::default()
+        };
+
+        // Legal
+        manager.update_feature(&id, set_status(Deprecated, false))?;
+        assert_eq!(manager.get_feature(&id).unwrap().status, Deprecated);
+
+        // Illegal, and nothing changes
+        let err = manager
+            .update_feature(&id, set_status(InProgress, false))
+            .unwrap_err();
+        assert!(err.to_string().contains("--force"), "{}", err);
+        assert_eq!(manager.get_feature(&id).unwrap().status, Deprecated);
+        assert_eq!(manager.history(&id)?.len(), 1);
+
+        // Forced
+        manager.update_feature(&id, set_status(Proposed, true))?;
+        assert_eq!(manager.get_feature(&id).unwrap().status, Proposed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overdue_features() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let now = Utc::now();
+
+        let late = manager.add_feature(NewFeature {
+            name: "Quarterly Report".to_string(),
+            due_date: Some(now - chrono::Duration::days(1)),
+            ..Default::default()
+        })?;
+        manager.add_feature(NewFeature {
+            name: "Annual Report".to_string(),
+            due_date: Some(now + chrono::Duration::days(1)),
+            ..Default::default()
+        })?;
+
+        // Reload to check the due dates survive a save
+        let manager = FeatureManager::new(temp_dir.path())?;
+        let overdue: Vec<&str> = manager.overdue().iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(overdue, vec![late.as_str()]);
+        assert_eq!(manager.statistics().overdue, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_due_date() {
+        assert_eq!(
+            parse_due_date("2030-01-31").unwrap().to_rfc3339(),
+            "2030-01-31T23:59:59+00:00"
+        );
+        assert_eq!(
+            parse_due_date("2030-01-31T09:00:00+02:00")
+                .unwrap()
+                .to_rfc3339(),
+            "2030-01-31T07:00:00+00:00"
+        );
+        assert!(parse_due_date("next week").is_err());
+    }
+
+    #[test]
+    fn test_complexity_is_validated_and_persisted() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let err = manager
+            .add_feature(NewFeature {
+                name: "Too Hard".to_string(),
+                complexity: Some(11),
+                ..Default::default()
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("between 1 and 10"), "{}", err);
+
+        let id = manager.add_feature(NewFeature {
+            name: "Just Right".to_string(),
+            complexity: Some(7),
+            ..Default::default()
+        })?;
+        assert!(manager
+            .update_feature(
+                &id,
+                FeatureUpdateRequest {
+                    complexity: Some(0),
+                    ..Default::default()
+                },
+            )
+            .is_err());
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        let feature = manager.get_feature(&id).unwrap();
+        assert_eq!(feature.complexity, Some(7));
+
+        // Harder features get proportionally larger effort estimates
+        let suggestions = manager.generate_feature_suggestions(
+            feature,
+            Some(SuggestionType::Optimization),
+            "high",
+        );
+        assert_eq!(suggestions[0].complexity, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_features_order_is_stable() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        for name in ["Zeta", "alpha", "Mu", "Beta", "Omega", "Kappa"] {
+            manager.add_feature(NewFeature {
+                name: name.to_string(),
+                ..Default::default()
+            })?;
+        }
+
+        let ids = |manager: &FeatureManager| -> Vec<String> {
+            manager
+                .list_features(None, None, None, None)
+                .iter()
+                .map(|feature| feature.id.clone())
+                .collect()
+        };
+        let first = ids(&manager);
+        assert_eq!(first, ids(&manager));
+        // A fresh load rebuilds the HashMap with a different layout
+        assert_eq!(first, ids(&FeatureManager::new(temp_dir.path())?));
+
+        let mut features = manager.list_features(None, None, None, None);
+        sort_features(&mut features, FeatureSort::Name);
+        let names: Vec<&str> = features.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "Beta", "Kappa", "Mu", "Omega", "Zeta"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_filter_match_any_and_all() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let mut add = |name: &str, tags: &[&str]| {
+            manager.add_feature(NewFeature {
+                name: name.to_string(),
+                tags: tags.iter().map(|tag| tag.to_string()).collect(),
+                ..Default::default()
+            })
+        };
+        let login = add("Login", &["security", "auth"])?;
+        let tokens = add("Tokens", &["auth"])?;
+        let scanning = add("Scanning", &["security"])?;
+        add("Themes", &["ui"])?;
+
+        let names = |tags: &[&str], tag_match| -> Vec<String> {
+            let mut ids: Vec<String> = manager
+                .list_matching(&FeatureFilter {
+                    tags: tags.iter().map(|tag| tag.to_string()).collect(),
+                    tag_match,
+                    ..Default::default()
+                })
+                .iter()
+                .map(|feature| feature.id.clone())
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        let mut any = vec![login.clone(), tokens.clone(), scanning.clone()];
+        any.sort();
+        assert_eq!(names(&["security", "auth"], TagMatch::Any), any);
+        assert_eq!(
+            names(&["security", "auth"], TagMatch::All),
+            vec![login.clone()]
+        );
+
+        // A single tag behaves the same either way
+        let mut security = vec![login.clone(), scanning];
+        security.sort();
+        assert_eq!(names(&["security"], TagMatch::Any), security);
+        assert_eq!(names(&["security"], TagMatch::All), security);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_does_not_create_directories() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        let manager = FeatureManager::open(temp_dir.path())?;
+        assert!(manager.list_features(None, None, None, None).is_empty());
+        assert!(!fargin_dir(temp_dir.path()).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_manager_creates_directory_on_first_add() -> Result<()> {
+        let temp_dir = tempdir()?;
+
+        let mut manager = FeatureManager::open(temp_dir.path())?;
+        let id = manager.add_feature(NewFeature {
+            name: "First".to_string(),
+            ..Default::default()
+        })?;
+        assert!(FeatureManager::open(temp_dir.path())?
+            .get_feature(&id)
+            .is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reload_only_parses_changed_files() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut writer = FeatureManager::new(temp_dir.path())?;
+        let mut ids = Vec::new();
+        for name in ["One", "Two", "Three"] {
+            ids.push(writer.add_feature(NewFeature {
+                name: name.to_string(),
+                ..Default::default()
+            })?);
+        }
+
+        let mut manager = FeatureManager::open(temp_dir.path())?;
+        assert_eq!(manager.parsed_files, 3);
+
+        manager.reload()?;
+        assert_eq!(manager.parsed_files, 3);
+
+        writer.update_feature(
+            &ids[1],
+            FeatureUpdateRequest {
+                description: Some("A longer description than before".to_string()),
+                ..Default::default()
+            },
+        )?;
+        writer.delete_feature(&ids[2])?;
+
+        manager.reload()?;
+        assert_eq!(manager.parsed_files, 4);
+        assert_eq!(
+            manager.get_feature(&ids[1]).unwrap().description.as_deref(),
+            Some("A longer description than before")
+        );
+        assert!(manager.get_feature(&ids[2]).is_none());
+        assert_eq!(manager.list_features(None, None, None, None).len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_load_matches_serial_load() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut writer = FeatureManager::new(temp_dir.path())?;
+        for i in 0..200 {
+            writer.add_feature(NewFeature {
+                name: format!("Feature {}", i),
+                description: Some(format!("Generated feature number {}", i)),
+                tags: vec![format!("batch-{}", i % 7)],
+                ..Default::default()
+            })?;
+        }
+
+        let serial = FeatureManager::open_with_threads(temp_dir.path(), 1)?;
+        let parallel = FeatureManager::open_with_threads(temp_dir.path(), 8)?;
+        assert_eq!(parallel.parsed_files, 200);
+
+        let names = |manager: &FeatureManager| -> Vec<(String, String)> {
+            manager
+                .list_features(None, None, None, None)
+                .into_iter()
+                .map(|feature| (feature.id.clone(), feature.name.clone()))
+                .collect()
+        };
+        assert_eq!(names(&serial).len(), 200);
+        assert_eq!(names(&serial), names(&parallel));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_names_are_detected() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let add = |manager: &mut FeatureManager, name: &str| {
+            manager.add_feature(NewFeature {
+                name: name.to_string(),
+                ..Default::default()
+            })
+        };
+
+        let first = add(&mut manager, "Login")?;
+        let other = add(&mut manager, "Logout")?;
+        let second = add(&mut manager, "login ")?;
+        assert_ne!(first, second);
+
+        let found: Vec<&str> = manager
+            .find_by_name("LOGIN")
+            .iter()
+            .map(|feature| feature.id.as_str())
+            .collect();
+        assert_eq!(found, vec![first.as_str(), second.as_str()]);
+
+        let stats = manager.statistics();
+        assert_eq!(stats.duplicate_names.len(), 1);
+        assert_eq!(
+            stats.duplicate_names.get("Login"),
+            Some(&vec![first.clone(), second.clone()])
+        );
+        assert!(!stats
+            .duplicate_names
+            .values()
+            .flatten()
+            .any(|id| *id == other));
+        assert!(stats.summary().contains("Duplicate Names"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_feature_is_feature_not_found() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        match manager.update_feature("nope", FeatureUpdateRequest::default()) {
+            Err(FarginError::FeatureNotFound(id)) => assert_eq!(id, "nope"),
+            other => panic!("expected FeatureNotFound, got {:?}", other),
+        }
+        assert!(matches!(
+            manager.rename("nope", "Anything"),
+            Err(FarginError::FeatureNotFound(_))
+        ));
+        assert!(matches!(
+            manager.rename("nope", " "),
+            Err(FarginError::Validation(_))
+        ));
+
+        Ok(())
+    }
 }
@@ -1,11 +1,14 @@
 use std::fmt;
+use std::fs;
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use clap::CommandFactory;
 
-use crate::cli::HowtoOutputFormat;
+use crate::cli::{Cli, HowtoOutputFormat};
+use crate::config::fargin_dir;
 use crate::features::FeatureStatus;
 
 /// Project Health and Checks
@@ -30,6 +33,7 @@ impl fmt::Display for FeatureStatus {
 
 /// ## Example: Basic Project Health Check
 #[allow(clippy::empty_line_after_doc_comments)]
+#[allow(clippy::duplicated_attributes)]
 /// ```rust
 /// use std::path::Path;
 /// use fargin::check::ProjectChecker;
@@ -56,8 +60,8 @@ impl fmt::Display for FeatureStatus {
 ///     let project_root = Path::new(".");
 ///     let checker = ProjectChecker::new(project_root);
 ///
-///     // Detailed feature health check
-///     let feature_health = checker.check_feature_health()?;
+///     // Detailed feature health check, flagging features untouched for 30 days
+///     let feature_health = checker.check_feature_health(30)?;
 ///     
 ///     // Analyze feature status distribution
 ///     let status_distribution = feature_health.status_distribution;
@@ -165,8 +169,31 @@ pub struct HowtoGenerator {
     verbosity: String,
     output_format: HowtoOutputFormat,
     save_path: Option<PathBuf>,
+    project_path: Option<PathBuf>,
 }
 
+/// Generator for a single built-in howto topic
+type TopicGenerator = fn(&HowtoGenerator) -> String;
+
+/// Registry of built-in howto topics
+///
+/// Both `list_topics` and `generate` read from this table, so a new topic only
+/// needs an entry here.
+const TOPICS: &[(&str, TopicGenerator)] = &[
+    ("check", HowtoGenerator::generate_project_checks_doc),
+    (
+        "feature-status",
+        HowtoGenerator::generate_feature_status_doc,
+    ),
+    (
+        "dependency-management",
+        HowtoGenerator::generate_dependency_doc,
+    ),
+    ("git-health", HowtoGenerator::generate_git_health_doc),
+    ("logging", HowtoGenerator::generate_logging_doc),
+    ("cli-usage", HowtoGenerator::generate_cli_usage_doc),
+];
+
 impl HowtoGenerator {
     /// Create a new HowtoGenerator
     pub fn new(
@@ -180,33 +207,92 @@ impl HowtoGenerator {
             verbosity,
             output_format,
             save_path,
+            project_path: None,
         }
     }
 
+    /// Also serve project-specific topics from `.fargin/docs/howto/<topic>.md`
+    ///
+    /// A project topic takes precedence over a built-in topic of the same name.
+    pub fn with_project_path(mut self, project_path: &Path) -> Self {
+        self.project_path = Some(project_path.to_path_buf());
+        self
+    }
+
     /// List all available howto topics
     pub fn list_topics() -> Vec<String> {
-        vec![
-            "check".to_string(),
-            "feature-status".to_string(),
-            "dependency-management".to_string(),
-            "git-health".to_string(),
-            "logging".to_string(),
-            "cli-usage".to_string(),
-        ]
+        TOPICS.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    /// Built-in topics merged with the project's own topics, sorted and deduplicated
+    pub fn available_topics(&self) -> Result<Vec<String>> {
+        let mut topics = Self::list_topics();
+        topics.extend(self.project_topics()?);
+        topics.sort();
+        topics.dedup();
+        Ok(topics)
+    }
+
+    /// Directory holding project-specific howto topics, if a project is set
+    fn project_howto_dir(&self) -> Option<PathBuf> {
+        self.project_path
+            .as_ref()
+            .map(|path| fargin_dir(path).join("docs/howto"))
+    }
+
+    /// Names of the markdown topics under `.fargin/docs/howto`
+    fn project_topics(&self) -> Result<Vec<String>> {
+        let Some(dir) = self.project_howto_dir().filter(|dir| dir.is_dir()) else {
+            return Ok(Vec::new());
+        };
+
+        let mut topics = Vec::new();
+        for entry in fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read howto directory {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) == Some("md") {
+                if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    topics.push(stem.to_string());
+                }
+            }
+        }
+
+        Ok(topics)
+    }
+
+    /// Contents of a project-specific topic, if one exists
+    fn project_topic_doc(&self, topic: &str) -> Result<Option<String>> {
+        // Topics are plain file stems; never let one escape the howto directory
+        if topic.contains(['/', '\\']) || topic.starts_with('.') {
+            return Ok(None);
+        }
+
+        let Some(path) = self
+            .project_howto_dir()
+            .map(|dir| dir.join(format!("{}.md", topic)))
+            .filter(|path| path.is_file())
+        else {
+            return Ok(None);
+        };
+
+        fs::read_to_string(&path)
+            .map(Some)
+            .with_context(|| format!("Failed to read howto topic {}", path.display()))
     }
 
     /// Generate howto documentation
     pub fn generate(&self) -> Result<String, anyhow::Error> {
         // Select documentation based on topic and verbosity
         let doc = match self.topic.as_deref() {
-            Some("check") => self.generate_project_checks_doc(),
-            Some("feature-status") => self.generate_feature_status_doc(),
-            Some("dependency-management") => self.generate_dependency_doc(),
-            Some("git-health") => self.generate_git_health_doc(),
-            Some("logging") => self.generate_logging_doc(),
-            Some("cli-usage") => self.generate_cli_usage_doc(),
+            Some(topic) => match self.project_topic_doc(topic)? {
+                Some(doc) => doc,
+                None => match TOPICS.iter().find(|(name, _)| *name == topic) {
+                    Some((_, generate_topic)) => generate_topic(self),
+                    None => return Err(unknown_topic_error(topic, &self.available_topics()?)),
+                },
+            },
             None => self.generate_overview_doc(),
-            _ => anyhow::bail!("Unknown howto topic"),
         };
 
         // Transform documentation based on output format
@@ -214,6 +300,10 @@ impl HowtoGenerator {
             HowtoOutputFormat::Terminal => doc,
             HowtoOutputFormat::Markdown => self.to_markdown(&doc),
             HowtoOutputFormat::Html => self.to_html(&doc),
+            HowtoOutputFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
+                "topic": self.topic,
+                "content": doc,
+            }))?,
         };
 
         // Save documentation if save path is provided
@@ -274,9 +364,9 @@ impl HowtoGenerator {
         "# Git Repository Health\n\n\
         Track repository development workflow.\n\n\
         ## Monitored Aspects\n\
-        . Commit frequency\n\
-        . Branch management\n\
-        . Merge conflict potential"
+        - Commit frequency\n\
+        - Branch management\n\
+        - Merge conflict potential"
             .to_string()
     }
 
@@ -293,14 +383,33 @@ impl HowtoGenerator {
     }
 
     /// Generate CLI usage documentation
+    ///
+    /// The command list is read from the clap definition, so it always matches
+    /// the commands the binary actually accepts.
     fn generate_cli_usage_doc(&self) -> String {
-        "# CLI Usage Guide\n\n\
-        Comprehensive guide to Fargin CLI commands.\n\n\
-        ## Available Commands\n\
-        . `check`: Run project health check\n\
-        . `config`: Manage project configuration\n\
-        . `status`: Display project status"
-            .to_string()
+        let commands = Cli::command()
+            .get_subcommands()
+            .map(|command| {
+                format!(
+                    "- `{}`: {}",
+                    command.get_name(),
+                    command
+                        .get_about()
+                        .map(|about| about.to_string())
+                        .unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "# CLI Usage Guide\n\n\
+            Comprehensive guide to Fargin CLI commands.\n\n\
+            ## Available Commands\n\
+            {}\n\n\
+            Run `fargin <command> --help` for the options of each command.\n",
+            commands
+        )
     }
 
     /// Generate overview documentation
@@ -311,18 +420,20 @@ impl HowtoGenerator {
             ## Available Topics\n\
             {}\n\
             Use `fargin howto <topic>` for detailed information.\n",
-            Self::list_topics().join("\n")
+            self.available_topics()
+                .unwrap_or_else(|_| Self::list_topics())
+                .join("\n")
         )
     }
 
     /// Convert documentation to Markdown
     fn to_markdown(&self, doc: &str) -> String {
-        doc.to_string() // In a real implementation, add Markdown-specific formatting
+        to_markdown(doc)
     }
 
     /// Convert documentation to HTML
     fn to_html(&self, doc: &str) -> String {
-        format!("<html><body><pre>{}</pre></body></html>", doc)
+        format!("<html><body>\n{}</body></html>", markdown_to_html(doc))
     }
 
     /// Save documentation to a file
@@ -336,5 +447,340 @@ impl HowtoGenerator {
     }
 }
 
+/// Normalize markdown documentation for publishing
+///
+/// Headings get a space after their `#` markers and a blank line before them,
+/// bare URLs are wrapped in angle brackets so every renderer links them, and a
+/// generated-by footer is appended. Fenced code blocks are left untouched.
+pub fn to_markdown(doc: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+
+    for line in doc.lines() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            lines.push(line.trim_end().to_string());
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) {
+            let title = trimmed[hashes..].trim();
+            if lines.last().is_some_and(|previous| !previous.is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("{} {}", "#".repeat(hashes), title));
+            continue;
+        }
+
+        lines.push(wrap_bare_urls(line.trim_end()));
+    }
+
+    let mut markdown = lines.join("\n").trim_end().to_string();
+    markdown.push_str(&format!(
+        "\n\n---\n\n_Generated by fargin {}_\n",
+        env!("CARGO_PKG_VERSION")
+    ));
+    markdown
+}
+
+/// Build the error for an unknown topic, suggesting the closest match
+fn unknown_topic_error(topic: &str, topics: &[String]) -> anyhow::Error {
+    let closest = topics
+        .iter()
+        .map(|name| (edit_distance(topic, name), name))
+        .min()
+        .filter(|(distance, name)| *distance <= name.len() / 2 || name.contains(topic))
+        .map(|(_, name)| format!(" Did you mean '{}'?", name))
+        .unwrap_or_default();
+
+    anyhow::anyhow!(
+        "Unknown howto topic '{}'.{} Valid topics: {}",
+        topic,
+        closest,
+        topics.join(", ")
+    )
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Escape a value for interpolation into HTML text or attribute values
+pub fn html_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render the markdown subset used by howto docs as HTML
+///
+/// Headings, `- ` lists, fenced code blocks and paragraphs are supported;
+/// every piece of text is escaped before it is emitted.
+pub fn markdown_to_html(doc: &str) -> String {
+    let mut html = String::new();
+    let mut in_list = false;
+    let mut in_code_block = false;
+
+    for line in doc.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            if in_list {
+                html.push_str("</ul>\n");
+                in_list = false;
+            }
+            html.push_str(if in_code_block {
+                "</code></pre>\n"
+            } else {
+                "<pre><code>"
+            });
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            html.push_str(&html_escape(line));
+            html.push('\n');
+            continue;
+        }
+
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            if !in_list {
+                html.push_str("<ul>\n");
+                in_list = true;
+            }
+            html.push_str(&format!("<li>{}</li>\n", html_escape(item)));
+            continue;
+        }
+
+        if in_list {
+            html.push_str("</ul>\n");
+            in_list = false;
+        }
+
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) {
+            html.push_str(&format!(
+                "<h{level}>{}</h{level}>\n",
+                html_escape(trimmed[hashes..].trim()),
+                level = hashes
+            ));
+        } else if !trimmed.is_empty() {
+            html.push_str(&format!("<p>{}</p>\n", html_escape(trimmed)));
+        }
+    }
+
+    if in_list {
+        html.push_str("</ul>\n");
+    }
+    if in_code_block {
+        html.push_str("</code></pre>\n");
+    }
+
+    html
+}
+
+/// Wrap `http(s)://` URLs that are not already part of a link in `<...>`
+fn wrap_bare_urls(line: &str) -> String {
+    line.split(' ')
+        .map(|word| {
+            if word.starts_with("http://") || word.starts_with("https://") {
+                let url = word.trim_end_matches(['.', ',', ';', ':', ')']);
+                format!("<{}>{}", url, &word[url.len()..])
+            } else {
+                word.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 // Re-export key types for documentation purposes
 pub use crate::check::{FeatureHealthReport, ProjectChecker, ProjectHealthReport};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_output_has_footer_and_no_double_fencing() -> Result<()> {
+        let generator = HowtoGenerator::new(
+            Some("check".to_string()),
+            "normal".to_string(),
+            HowtoOutputFormat::Markdown,
+            None,
+        );
+        let doc = generator.generate()?;
+
+        assert!(doc.contains("_Generated by fargin"));
+        assert!(doc.ends_with('\n'));
+        assert!(!doc.contains("```markdown"));
+        assert_eq!(doc.matches("```").count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_markdown_normalizes_headings_and_urls() {
+        let markdown =
+            to_markdown("#Title\nSee https://example.com.\n## Next\n```\n#not-a-heading\n```");
+
+        assert!(markdown.starts_with("# Title\n"));
+        assert!(markdown.contains("See <https://example.com>."));
+        assert!(markdown.contains("\n\n## Next\n"));
+        assert!(markdown.contains("\n#not-a-heading\n"));
+    }
+
+    #[test]
+    fn test_html_output_escapes_and_renders_structure() {
+        assert_eq!(
+            html_escape(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+
+        let html = markdown_to_html("# A < B\n- one & two\n- three\n```\n<tag>\n```\ntext");
+        assert_eq!(
+            html,
+            "<h1>A &lt; B</h1>\n<ul>\n<li>one &amp; two</li>\n<li>three</li>\n</ul>\n\
+             <pre><code>&lt;tag&gt;\n</code></pre>\n<p>text</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_json_output_wraps_topic_and_content() -> Result<()> {
+        let generator = HowtoGenerator::new(
+            Some("logging".to_string()),
+            "normal".to_string(),
+            HowtoOutputFormat::Json,
+            None,
+        );
+        let json: serde_json::Value = serde_json::from_str(&generator.generate()?)?;
+
+        assert_eq!(json["topic"], "logging");
+        assert!(json["content"]
+            .as_str()
+            .unwrap()
+            .starts_with("# Logging and Observability"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cli_usage_doc_lists_every_command() -> Result<()> {
+        let generator = HowtoGenerator::new(
+            Some("cli-usage".to_string()),
+            "normal".to_string(),
+            HowtoOutputFormat::Terminal,
+            None,
+        );
+        let doc = generator.generate()?;
+
+        for command in Cli::command().get_subcommands() {
+            assert!(
+                doc.contains(&format!("- `{}`: ", command.get_name())),
+                "missing command {} in:\n{}",
+                command.get_name(),
+                doc
+            );
+        }
+        assert!(!doc.lines().any(|line| line.starts_with(". ")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_topic_registry_matches_dispatch() -> Result<()> {
+        let listed: std::collections::BTreeSet<String> =
+            HowtoGenerator::list_topics().into_iter().collect();
+        let registered: std::collections::BTreeSet<String> =
+            TOPICS.iter().map(|(name, _)| name.to_string()).collect();
+        assert_eq!(listed, registered);
+        assert_eq!(listed.len(), TOPICS.len(), "duplicate topic names");
+
+        for topic in listed {
+            let generator = HowtoGenerator::new(
+                Some(topic.clone()),
+                "normal".to_string(),
+                HowtoOutputFormat::Terminal,
+                None,
+            );
+            assert!(!generator.generate()?.is_empty(), "empty doc for {}", topic);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_topic_suggests_closest() {
+        let generator = HowtoGenerator::new(
+            Some("git-helth".to_string()),
+            "normal".to_string(),
+            HowtoOutputFormat::Terminal,
+            None,
+        );
+        let error = generator.generate().unwrap_err().to_string();
+
+        assert!(error.contains("Did you mean 'git-health'?"));
+        assert!(error.contains("cli-usage"));
+    }
+
+    #[test]
+    fn test_project_topic_from_docs_dir() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let howto_dir = temp_dir.path().join(".fargin/docs/howto");
+        fs::create_dir_all(&howto_dir)?;
+        fs::write(
+            howto_dir.join("deployment.md"),
+            "# Deploying\n\nRun `make release`.\n",
+        )?;
+
+        let generator = HowtoGenerator::new(
+            Some("deployment".to_string()),
+            "normal".to_string(),
+            HowtoOutputFormat::Terminal,
+            None,
+        )
+        .with_project_path(temp_dir.path());
+
+        assert_eq!(
+            generator.generate()?,
+            "# Deploying\n\nRun `make release`.\n"
+        );
+
+        let topics = generator.available_topics()?;
+        assert!(topics.contains(&"deployment".to_string()));
+        assert!(topics.contains(&"git-health".to_string()));
+
+        Ok(())
+    }
+}
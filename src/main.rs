@@ -1,156 +1,107 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 use fargin::cli::{
-    CheckOperation, Cli, Commands, DesignOperation, FeatureOperation, HowtoOutputFormat,
-    InitOperation,
+    CheckOperation, Cli, Commands, ConfigOperation, CountOutputFormat, DesignOperation,
+    ExportFormat, FeatureOperation, GraphFormat, HowtoOutputFormat, InitOperation,
+    ProgressOutputFormat, RunOutputFormat,
 };
 use fargin::config::ProjectConfig;
 use fargin::features::FeatureManager;
+use fargin::howto::html_escape;
+use fargin::{eoutln, out, outln};
 use std::fs;
-use std::path::Path;
 
-struct ProjectChecker {
-    // ...
-}
-
-impl ProjectChecker {
-    fn new(_path: &Path) -> Self {
-        // Placeholder implementation
-        ProjectChecker {}
-    }
-
-    #[allow(dead_code)]
-    fn run_all_checks(&self) -> Result<String> {
-        // Placeholder implementation
-        Ok("All checks completed".to_string())
-    }
-
-    #[allow(dead_code)]
-    fn check_feature_health(&self) -> Result<FeatureHealth> {
-        // Placeholder implementation
-        Ok(FeatureHealth {
-            total_features: 0,
-            status_distribution: vec![],
-            stale_features: vec![],
-        })
-    }
-
-    #[allow(dead_code)]
-    fn check_file_structure(&self) -> Result<StructureReport> {
-        // Placeholder implementation
-        Ok(StructureReport {
-            existing_dirs: vec![],
-            missing_dirs: vec![],
-        })
-    }
-
-    #[allow(dead_code)]
-    fn check_dependencies(&self) -> Result<DependencyReport> {
-        // Placeholder implementation
-        Ok(DependencyReport {
-            total_dependencies: 0,
-            outdated_dependencies: vec![],
-        })
-    }
-
-    #[allow(dead_code)]
-    fn check_git_status(&self) -> Result<GitReport> {
-        // Placeholder implementation
-        Ok(GitReport {
-            is_git_repo: false,
-            branch_name: None,
-            uncommitted_changes: 0,
-            unpushed_commits: 0,
-        })
-    }
-
-    fn run_project_checks(&self) -> Result<()> {
-        // Placeholder implementation
-        Ok(())
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {:#}", e);
+        std::process::exit(fargin::exit_code(&e));
     }
 }
 
-#[allow(dead_code)]
-struct FeatureHealth {
-    total_features: usize,
-    status_distribution: Vec<(String, usize)>,
-    stale_features: Vec<String>,
-}
-
-#[allow(dead_code)]
-struct StructureReport {
-    existing_dirs: Vec<String>,
-    missing_dirs: Vec<String>,
-}
-
-#[allow(dead_code)]
-struct DependencyReport {
-    total_dependencies: usize,
-    outdated_dependencies: Vec<String>,
-}
-
-#[allow(dead_code)]
-struct GitReport {
-    is_git_repo: bool,
-    branch_name: Option<String>,
-    uncommitted_changes: usize,
-    unpushed_commits: usize,
-}
-
-fn main() -> Result<()> {
+fn run() -> Result<()> {
     let cli = Cli::parse();
+    fargin::init_logging(cli.log_level());
+    fargin::output::init(cli.no_color);
 
     match cli.command {
         Commands::Init { operation } => match operation {
             InitOperation::Rust {
                 name,
                 path,
-                cargo_bin: _,
-                template: _,
-                with_fargin: _,
+                cargo_bin,
+                template,
+                with_fargin,
+                with_ci,
+                force,
                 dry_run,
+                output,
             } => {
-                let config = ProjectConfig::new(name.clone(), "Rust project".to_string());
-
-                if !dry_run {
-                    config.save(path.as_path())?;
+                let plan = fargin::config::init_rust_project(
+                    name,
+                    path,
+                    cargo_bin,
+                    template,
+                    with_fargin,
+                    with_ci,
+                    force,
+                    dry_run,
+                )?;
+                if dry_run {
+                    fargin::print_init_plan(&plan, output)?;
                 }
-
                 Ok(())
             }
             InitOperation::Template {
-                template: _,
+                template,
                 name,
                 path,
-                with_fargin: _,
+                with_fargin,
+                with_ci,
+                force,
                 dry_run,
+                output,
             } => {
-                let config = ProjectConfig::new(name.clone(), "Template project".to_string());
-
-                if !dry_run {
-                    config.save(path.as_path())?;
+                let plan = fargin::config::init_template_project(
+                    template,
+                    name,
+                    path,
+                    with_fargin,
+                    with_ci,
+                    force,
+                    dry_run,
+                )?;
+                if dry_run {
+                    fargin::print_init_plan(&plan, output)?;
                 }
-
                 Ok(())
             }
             InitOperation::Minimal {
                 name,
                 path,
-                project_type: _,
-                with_fargin: _,
+                project_type,
+                with_fargin,
+                with_ci,
+                force,
                 dry_run,
+                output,
             } => {
-                let config = ProjectConfig::new(name.clone(), "Minimal project".to_string());
-
-                if !dry_run {
-                    config.save(path.as_path())?;
+                let plan = fargin::config::init_minimal_project(
+                    name,
+                    path,
+                    project_type,
+                    with_fargin,
+                    with_ci,
+                    force,
+                    dry_run,
+                )?;
+                if dry_run {
+                    fargin::print_init_plan(&plan, output)?;
                 }
-
                 Ok(())
             }
         },
         Commands::Feature { operation, path } => {
-            let mut feature_manager = FeatureManager::new(path.as_path())?;
+            let mut feature_manager = FeatureManager::open(path.as_path())?;
 
             match operation {
                 FeatureOperation::Add {
@@ -159,57 +110,127 @@ fn main() -> Result<()> {
                     tags,
                     priority,
                     assigned_to,
+                    acceptance_criteria,
+                    related_features,
+                    due,
+                    complexity,
                 } => {
-                    let feature_id = feature_manager.add_feature(
+                    let feature_id = feature_manager.add_feature(fargin::features::NewFeature {
                         name,
                         description,
-                        tags,
+                        tags: tags.unwrap_or_default(),
                         priority,
                         assigned_to,
-                    )?;
-                    println!("Feature added with ID: {}", feature_id);
+                        acceptance_criteria: acceptance_criteria.unwrap_or_default(),
+                        related_features: related_features.unwrap_or_default(),
+                        due_date: due,
+                        complexity,
+                    })?;
+                    outln!("Feature added with ID: {}", feature_id);
                     Ok(())
                 }
                 FeatureOperation::List {
                     tag,
+                    match_any: _,
+                    match_all,
                     status,
                     priority,
+                    assigned_to,
+                    sort,
+                    output,
                 } => {
-                    let features = feature_manager.list_features(tag.as_deref(), status, priority);
+                    let mut features =
+                        feature_manager.list_matching(&fargin::features::FeatureFilter {
+                            tags: tag,
+                            tag_match: if match_all {
+                                fargin::features::TagMatch::All
+                            } else {
+                                fargin::features::TagMatch::Any
+                            },
+                            status,
+                            priority,
+                            assigned_to,
+                        });
+                    if let Some(sort) = sort {
+                        fargin::features::sort_features(&mut features, sort);
+                    }
 
-                    if features.is_empty() {
-                        println!("No features found.");
-                    } else {
-                        println!("Features:");
-                        for feature in features {
-                            println!(
-                                "ID: {}, Name: {}, Status: {:?}, Priority: {:?}",
-                                feature.id, feature.name, feature.status, feature.priority
-                            );
+                    match output {
+                        HowtoOutputFormat::Terminal => {
+                            if features.is_empty() {
+                                outln!("No features found.");
+                            } else {
+                                outln!("Features:");
+                                for feature in features {
+                                    outln!(
+                                        "ID: {}, Name: {}, Status: {:?}, Priority: {:?}",
+                                        feature.id,
+                                        feature.name,
+                                        feature.status,
+                                        feature.priority
+                                    );
+                                }
+                            }
+                        }
+                        HowtoOutputFormat::Markdown => {
+                            print!("{}", fargin::features::features_markdown_table(&features));
+                        }
+                        HowtoOutputFormat::Html => {
+                            println!("{}", fargin::features::features_html_table(&features));
+                        }
+                        HowtoOutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&features)?);
                         }
                     }
                     Ok(())
                 }
                 FeatureOperation::Show { id } => match feature_manager.get_feature(&id) {
                     Some(feature) => {
-                        println!("Feature Details:");
-                        println!("ID: {}", feature.id);
-                        println!("Name: {}", feature.name);
-                        println!(
+                        outln!("Feature Details:");
+                        outln!("ID: {}", feature.id);
+                        outln!("Name: {}", feature.name);
+                        outln!(
                             "Description: {}",
                             feature.description.as_deref().unwrap_or("No description")
                         );
-                        println!("Status: {:?}", feature.status);
-                        println!("Priority: {:?}", feature.priority);
-                        println!("Tags: {:?}", feature.tags);
-                        println!(
+                        outln!("Status: {:?}", feature.status);
+                        outln!("Priority: {:?}", feature.priority);
+                        outln!("Tags: {:?}", feature.tags);
+                        outln!(
                             "Assigned To: {}",
                             feature.assigned_to.as_deref().unwrap_or("Unassigned")
                         );
+                        if let Some(due) = feature.due_date {
+                            outln!("Due Date: {}", due.to_rfc3339());
+                        }
+                        if !feature.related_features.is_empty() {
+                            outln!("Related Features: {}", feature.related_features.join(", "));
+                        }
+                        if !feature.acceptance_criteria.is_empty() {
+                            outln!("Acceptance Criteria:");
+                            for criterion in &feature.acceptance_criteria {
+                                outln!("  - {}", criterion);
+                            }
+                        }
                         Ok(())
                     }
                     None => Err(anyhow::anyhow!("Feature not found")),
                 },
+                FeatureOperation::History { id } => {
+                    let history = feature_manager.history(&id)?;
+                    if history.is_empty() {
+                        outln!("No status changes recorded for {}", id);
+                    }
+                    for change in history {
+                        outln!(
+                            "{}  {} -> {}",
+                            change.timestamp.to_rfc3339(),
+                            change.from,
+                            change.to
+                        );
+                    }
+                    Ok(())
+                }
                 FeatureOperation::Update {
                     id,
                     description,
@@ -217,6 +238,11 @@ fn main() -> Result<()> {
                     tags,
                     priority,
                     assigned_to,
+                    acceptance_criteria,
+                    related_features,
+                    due,
+                    complexity,
+                    force,
                 } => {
                     feature_manager.update_feature(
                         &id,
@@ -226,15 +252,167 @@ fn main() -> Result<()> {
                             tags,
                             priority,
                             assigned_to,
+                            acceptance_criteria,
+                            related_features,
+                            due_date: due,
+                            complexity,
+                            force,
                             ..Default::default()
                         },
                     )?;
-                    println!("Feature {} updated successfully", id);
+                    outln!("Feature {} updated successfully", id);
+                    Ok(())
+                }
+                FeatureOperation::Rename {
+                    id,
+                    new_name,
+                    new_id,
+                } => {
+                    feature_manager.rename(&id, &new_name)?;
+                    outln!("Feature {} renamed to {}", id, new_name);
+
+                    if let Some(new_id) = new_id {
+                        let rewritten = feature_manager.reassign_id(&id, &new_id)?;
+                        outln!("Feature {} moved to ID {}", id, new_id);
+                        for referencing in rewritten {
+                            outln!("  Updated reference in {}", referencing);
+                        }
+                    }
+                    Ok(())
+                }
+                FeatureOperation::UpdateAll {
+                    status,
+                    tag,
+                    priority,
+                    assigned_to,
+                    set_status,
+                    set_priority,
+                    set_assigned_to,
+                    force,
+                } => {
+                    let filter = fargin::features::FeatureFilter {
+                        tags: tag.into_iter().collect(),
+                        status,
+                        priority,
+                        assigned_to,
+                        ..Default::default()
+                    };
+                    let updates = fargin::features::FeatureUpdateRequest {
+                        status: set_status,
+                        priority: set_priority,
+                        assigned_to: set_assigned_to,
+                        force,
+                        ..Default::default()
+                    };
+
+                    let changed = feature_manager.update_matching(filter, updates)?;
+                    outln!("Updated {} feature(s)", changed);
                     Ok(())
                 }
                 FeatureOperation::Remove { id } => {
                     feature_manager.delete_feature(&id)?;
-                    println!("Feature {} deleted successfully", id);
+                    outln!("Feature {} deleted successfully", id);
+                    Ok(())
+                }
+                FeatureOperation::Triage {
+                    tag,
+                    status,
+                    priority,
+                } => {
+                    let stdin = std::io::stdin();
+                    let summary = feature_manager.triage(
+                        tag.as_deref(),
+                        Some(status),
+                        priority,
+                        &mut stdin.lock(),
+                        &mut std::io::stdout(),
+                    )?;
+                    outln!(
+                        "\n✅ Triage finished: {} updated, {} skipped, {} remaining",
+                        summary.updated,
+                        summary.skipped,
+                        summary.remaining
+                    );
+                    Ok(())
+                }
+                FeatureOperation::Search { query } => {
+                    let results = feature_manager.search(&query);
+                    if results.is_empty() {
+                        outln!("No features match {:?}.", query);
+                    } else {
+                        outln!("Features matching {:?}:", query);
+                        for feature in results {
+                            outln!(
+                                "ID: {}, Name: {}, Status: {:?}, Priority: {:?}",
+                                feature.id,
+                                feature.name,
+                                feature.status,
+                                feature.priority
+                            );
+                        }
+                    }
+                    Ok(())
+                }
+                FeatureOperation::Stats { output } => {
+                    let stats = feature_manager.statistics();
+                    match output {
+                        HowtoOutputFormat::Terminal => out!("{}", stats.summary()),
+                        HowtoOutputFormat::Markdown => print!("{}", stats.to_markdown()),
+                        HowtoOutputFormat::Html => {
+                            println!("<pre>{}</pre>", html_escape(&stats.summary()))
+                        }
+                        HowtoOutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&stats)?)
+                        }
+                    }
+                    Ok(())
+                }
+                FeatureOperation::CheckDeps => {
+                    let cycles = feature_manager.detect_dependency_cycles();
+                    if cycles.is_empty() {
+                        outln!("No dependency cycles found");
+                    } else {
+                        outln!("⚠️ Found {} dependency cycle(s):", cycles.len());
+                        for cycle in cycles {
+                            let mut path = cycle.clone();
+                            path.push(cycle[0].clone());
+                            outln!("  {}", path.join(" -> "));
+                        }
+                    }
+                    Ok(())
+                }
+                FeatureOperation::Merge {
+                    keep_id,
+                    drop_id,
+                    prefer,
+                } => {
+                    let summary = feature_manager.merge_features(&keep_id, &drop_id, prefer)?;
+                    outln!(
+                        "🔀 Merged {} ({}) into {} ({})",
+                        summary.dropped.id,
+                        summary.dropped.name,
+                        summary.kept.id,
+                        summary.kept.name
+                    );
+                    if !summary.added_tags.is_empty() {
+                        outln!("  Tags added: {}", summary.added_tags.join(", "));
+                    }
+                    if !summary.added_criteria.is_empty() {
+                        outln!(
+                            "  Acceptance criteria added: {}",
+                            summary.added_criteria.len()
+                        );
+                    }
+                    outln!("  Priority: {:?}", summary.kept.priority);
+                    outln!(
+                        "  Status: {}, Assigned To: {}",
+                        summary.kept.status,
+                        summary.kept.assigned_to.as_deref().unwrap_or("Unassigned")
+                    );
+                    if !summary.repointed.is_empty() {
+                        outln!("  References updated: {}", summary.repointed.join(", "));
+                    }
+                    outln!("  Archived: {}", summary.archived_path.display());
                     Ok(())
                 }
                 FeatureOperation::Suggest {
@@ -243,6 +421,7 @@ fn main() -> Result<()> {
                     verbosity,
                     output,
                     save_path,
+                    explain,
                 } => {
                     // Retrieve the feature
                     let feature = match feature_manager.get_feature(&id) {
@@ -258,31 +437,38 @@ fn main() -> Result<()> {
                     );
 
                     // Format and display suggestions
-                    if suggestions.is_empty() {
-                        println!("No suggestions found for feature: {}", id);
+                    if suggestions.is_empty() && !matches!(output, HowtoOutputFormat::Json) {
+                        outln!("No suggestions found for feature: {}", id);
                         return Ok(());
                     }
 
                     // Output formatting based on selected format
                     match output {
                         HowtoOutputFormat::Terminal => {
-                            println!("Suggestions for Feature: {}", feature.name);
+                            outln!("Suggestions for Feature: {}", feature.name);
                             for suggestion in suggestions {
-                                println!("\n🔹 Suggestion ID: {}", suggestion.id);
-                                println!("   Type: {:?}", suggestion.suggestion_type);
-                                println!("   Content: {}", suggestion.content);
-                                println!("   Confidence: {:.2}%", suggestion.confidence * 100.0);
-                                println!("   Complexity: {}/10", suggestion.complexity);
-                                println!("   Impact: {:?}", suggestion.impact);
+                                outln!("\n🔹 Suggestion ID: {}", suggestion.id);
+                                outln!("   Type: {:?}", suggestion.suggestion_type);
+                                outln!("   Content: {}", suggestion.content);
+                                outln!("   Confidence: {:.2}%", suggestion.confidence * 100.0);
+                                outln!("   Complexity: {}/10", suggestion.complexity);
+                                outln!("   Impact: {:?}", suggestion.impact);
 
                                 if !suggestion.tags.is_empty() {
-                                    println!("   Tags: {}", suggestion.tags.join(", "));
+                                    outln!("   Tags: {}", suggestion.tags.join(", "));
                                 }
 
                                 if !suggestion.next_steps.is_empty() {
-                                    println!("   Next Steps:");
+                                    outln!("   Next Steps:");
                                     for (i, step) in suggestion.next_steps.iter().enumerate() {
-                                        println!("   {}. {}", i + 1, step);
+                                        outln!("   {}. {}", i + 1, step);
+                                    }
+                                }
+
+                                if explain {
+                                    outln!("   Reasons:");
+                                    for reason in &suggestion.reasons {
+                                        outln!("   - {}", reason);
                                     }
                                 }
                             }
@@ -322,13 +508,20 @@ fn main() -> Result<()> {
                                         markdown.push_str(&format!("{}. {}\n", i + 1, step));
                                     }
                                 }
+
+                                if explain {
+                                    markdown.push_str("### Reasons:\n\n");
+                                    for reason in &suggestion.reasons {
+                                        markdown.push_str(&format!("- {}\n", reason));
+                                    }
+                                }
                                 markdown.push_str("\n---\n\n");
                             }
 
                             // If save_path is provided, save the markdown
                             if let Some(path) = save_path {
                                 fs::write(&path, &markdown)?;
-                                println!("Suggestions saved to: {}", path.display());
+                                outln!("Suggestions saved to: {}", path.display());
                             } else {
                                 println!("{}", markdown);
                             }
@@ -351,7 +544,8 @@ fn main() -> Result<()> {
 <body>
     <h1>Suggestions for Feature: {}</h1>
 ",
-                                feature.name, feature.name
+                                html_escape(&feature.name),
+                                html_escape(&feature.name)
                             );
 
                             for suggestion in suggestions {
@@ -365,9 +559,9 @@ fn main() -> Result<()> {
         <p><strong>Complexity</strong>: {}/10</p>
         <p><strong>Impact</strong>: {:#?}</p>
 ",
-                                    suggestion.id,
+                                    html_escape(&suggestion.id),
                                     suggestion.suggestion_type,
-                                    suggestion.content,
+                                    html_escape(&suggestion.content),
                                     suggestion.confidence * 100.0,
                                     suggestion.complexity,
                                     suggestion.impact
@@ -376,7 +570,10 @@ fn main() -> Result<()> {
                                 if !suggestion.tags.is_empty() {
                                     html.push_str("<p><strong>Tags</strong>: ");
                                     for tag in &suggestion.tags {
-                                        html.push_str(&format!("<span class='tag'>{}</span>", tag));
+                                        html.push_str(&format!(
+                                            "<span class='tag'>{}</span>",
+                                            html_escape(tag)
+                                        ));
                                     }
                                     html.push_str("</p>");
                                 }
@@ -384,11 +581,19 @@ fn main() -> Result<()> {
                                 if !suggestion.next_steps.is_empty() {
                                     html.push_str("<h3>Next Steps:</h3><ol>");
                                     for step in &suggestion.next_steps {
-                                        html.push_str(&format!("<li>{}</li>", step));
+                                        html.push_str(&format!("<li>{}</li>", html_escape(step)));
                                     }
                                     html.push_str("</ol>");
                                 }
 
+                                if explain {
+                                    html.push_str("<h3>Reasons:</h3><ul>");
+                                    for reason in &suggestion.reasons {
+                                        html.push_str(&format!("<li>{}</li>", html_escape(reason)));
+                                    }
+                                    html.push_str("</ul>");
+                                }
+
                                 html.push_str("</div>");
                             }
 
@@ -397,237 +602,412 @@ fn main() -> Result<()> {
                             // If save_path is provided, save the HTML
                             if let Some(path) = save_path {
                                 fs::write(&path, &html)?;
-                                println!("Suggestions saved to: {}", path.display());
+                                outln!("Suggestions saved to: {}", path.display());
                             } else {
                                 println!("{}", html);
                             }
                         }
+                        HowtoOutputFormat::Json => {
+                            let json = serde_json::to_string_pretty(&suggestions)?;
+
+                            // If save_path is provided, save the JSON
+                            if let Some(path) = save_path {
+                                fs::write(&path, &json)?;
+                                outln!("Suggestions saved to: {}", path.display());
+                            } else {
+                                println!("{}", json);
+                            }
+                        }
                     }
 
                     Ok(())
                 }
-            }
-        }
-        Commands::Design { operation, path } => {
-            match operation {
-                DesignOperation::Create { name, description } => {
-                    // Create a design document in the .fargin/docs directory
-                    let design_path = path.join(".fargin/docs");
-                    fs::create_dir_all(&design_path)?;
-
-                    // Generate a timestamp-based filename
-                    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S").to_string();
-                    let slug = name
-                        .to_lowercase()
-                        .replace(char::is_whitespace, "_")
-                        .chars()
-                        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
-                        .collect::<String>();
-
-                    let filename = format!("{}__{}.md", timestamp, slug);
-                    let full_path = design_path.join(filename);
-
-                    let design_content = format!(
-                        "# Design: {}\n\n## Description\n{}\n\n## Created\n{}\n\n## Status\nDraft\n",
-                        name,
-                        description.unwrap_or_else(|| "No description provided".to_string()),
-                        chrono::Local::now().to_rfc2822()
-                    );
+                FeatureOperation::Bump { ids } => {
+                    for id in ids {
+                        let (old, new) = feature_manager.raise_priority(&id)?;
+                        outln!("Feature {} priority: {:?} → {:?}", id, old, new);
+                    }
+                    Ok(())
+                }
+                FeatureOperation::Lower { ids } => {
+                    for id in ids {
+                        let (old, new) = feature_manager.lower_priority(&id)?;
+                        outln!("Feature {} priority: {:?} → {:?}", id, old, new);
+                    }
+                    Ok(())
+                }
+                FeatureOperation::Rank { id, above, below } => {
+                    let (other, placement) = match (above, below) {
+                        (Some(other), _) => (other, fargin::features::RankPlacement::Above),
+                        (None, Some(other)) => (other, fargin::features::RankPlacement::Below),
+                        (None, None) => return Err(anyhow::anyhow!("Specify --above or --below")),
+                    };
 
-                    fs::write(&full_path, design_content)?;
+                    let rank = feature_manager.rank_feature(&id, &other, placement)?;
+                    outln!("Feature {} ranked at {}", id, rank);
+                    Ok(())
+                }
+                FeatureOperation::CountBy { field, output } => {
+                    let counts = feature_manager.count_by(field);
 
-                    println!("Design document created: {}", full_path.display());
+                    match output {
+                        CountOutputFormat::Terminal => {
+                            if counts.is_empty() {
+                                outln!("No features found.");
+                            }
+                            for (value, count) in counts {
+                                outln!("{}: {}", value, count);
+                            }
+                        }
+                        CountOutputFormat::Json => {
+                            let map: serde_json::Map<String, serde_json::Value> = counts
+                                .into_iter()
+                                .map(|(value, count)| (value, count.into()))
+                                .collect();
+                            println!("{}", serde_json::to_string_pretty(&map)?);
+                        }
+                    }
                     Ok(())
                 }
-                DesignOperation::List => {
-                    // List existing design documents
-                    let design_path = path.join(".fargin/docs");
+                FeatureOperation::ImplementOrder { tag } => {
+                    let order = feature_manager.implementation_order(tag.as_deref())?;
 
-                    if !design_path.exists() {
-                        println!("No design documents found. Use 'fargin design create' to add a design.");
-                        return Ok(());
+                    if order.is_empty() {
+                        outln!("No features left to implement.");
+                    } else {
+                        outln!("Implementation order:");
+                        for (i, feature) in order.iter().enumerate() {
+                            outln!("{}. {} ({})", i + 1, feature.name, feature.id);
+                        }
                     }
+                    Ok(())
+                }
+                FeatureOperation::Graph { format } => {
+                    match format {
+                        GraphFormat::Mermaid => print!("{}", feature_manager.to_mermaid()),
+                    }
+                    Ok(())
+                }
+                FeatureOperation::Export {
+                    tag,
+                    status,
+                    priority,
+                    format,
+                    output,
+                } => {
+                    let document = match format {
+                        ExportFormat::Markdown => {
+                            feature_manager.export_markdown(tag.as_deref(), status, priority)
+                        }
+                        ExportFormat::Json => {
+                            if tag.is_some() || status.is_some() || priority.is_some() {
+                                return Err(anyhow::anyhow!(
+                                    "--tag, --status and --priority only apply to markdown exports"
+                                ));
+                            }
+                            feature_manager.export_json()?
+                        }
+                    };
 
-                    let mut designs = fs::read_dir(&design_path)?
-                        .filter_map(|entry| {
-                            entry.ok().and_then(|e| {
-                                let path = e.path();
-                                if path.extension().and_then(|e| e.to_str()) == Some("md") {
-                                    path.file_stem()
-                                        .and_then(|n| n.to_str())
-                                        .map(|n| n.to_string())
-                                } else {
-                                    None
-                                }
-                            })
-                        })
-                        .collect::<Vec<_>>();
+                    match output {
+                        Some(path) => {
+                            if let Some(parent) = path.parent() {
+                                fs::create_dir_all(parent)?;
+                            }
+                            fs::write(&path, document)?;
+                            outln!("Features exported to {}", path.display());
+                        }
+                        None => println!("{}", document),
+                    }
+                    Ok(())
+                }
+                FeatureOperation::Import { file, on_conflict } => {
+                    let json = fs::read_to_string(&file)
+                        .with_context(|| format!("Failed to read {}", file.display()))?;
+                    let summary = feature_manager.import_json(&json, on_conflict)?;
 
-                    // Sort designs chronologically
-                    designs.sort();
+                    for (old_id, new_id) in &summary.renamed {
+                        outln!("Renamed {} -> {}", old_id, new_id);
+                    }
+                    for id in &summary.skipped {
+                        outln!("Skipped existing feature {}", id);
+                    }
+                    outln!(
+                        "Imported {} feature(s), skipped {}",
+                        summary.imported.len(),
+                        summary.skipped.len()
+                    );
+                    Ok(())
+                }
+                FeatureOperation::ImportChecklist { file } => {
+                    let markdown = fs::read_to_string(&file)
+                        .with_context(|| format!("Failed to read {}", file.display()))?;
+                    let ids = feature_manager.import_checklist(&markdown)?;
+
+                    for id in &ids {
+                        outln!("Feature added with ID: {}", id);
+                    }
+                    outln!("Imported {} feature(s) from {}", ids.len(), file.display());
+                    Ok(())
+                }
+            }
+        }
+        Commands::Design { operation, path } => {
+            let mut design_manager = fargin::design::DesignManager::new(&path)?;
 
+            match operation {
+                DesignOperation::Create { name, description } => {
+                    let id = design_manager.create(name, description)?;
+                    outln!("Design document created with ID: {}", id);
+                    Ok(())
+                }
+                DesignOperation::List => {
+                    let designs = design_manager.list();
                     if designs.is_empty() {
-                        println!("No design documents found.");
+                        outln!(
+                            "No design documents found. Use 'fargin design create' to add a design."
+                        );
                     } else {
-                        println!("Existing design documents:");
+                        outln!("Existing design documents:");
                         for design in designs {
-                            println!("- {}", design);
+                            outln!("- {} ({}) [{}]", design.id, design.name, design.status);
                         }
                     }
                     Ok(())
                 }
-                DesignOperation::Show { id } => {
-                    // Show details of a specific design document
-                    let design_path = path.join(format!(".fargin/docs/{}.md", id));
-
-                    if !design_path.exists() {
-                        return Err(anyhow::anyhow!("Design document '{}' not found", id));
+                DesignOperation::Show { id } => match design_manager.get(&id) {
+                    Some(design) => {
+                        outln!("Design Details:");
+                        outln!("ID: {}", design.id);
+                        outln!("Name: {}", design.name);
+                        outln!("Status: {}", design.status);
+                        outln!("Created At: {}", design.created_at.to_rfc3339());
+                        outln!(
+                            "Description: {}",
+                            design
+                                .description
+                                .as_deref()
+                                .unwrap_or("No description provided")
+                        );
+                        Ok(())
                     }
-
-                    let content = fs::read_to_string(&design_path)?;
-                    println!("Design Document: {}\n", id);
-                    println!("{}", content);
+                    None => Err(anyhow::anyhow!("Design document '{}' not found", id)),
+                },
+                DesignOperation::Update {
+                    id,
+                    status,
+                    description,
+                    force,
+                } => {
+                    design_manager.update(
+                        &id,
+                        fargin::design::DesignUpdateRequest {
+                            description,
+                            status,
+                            force,
+                            ..Default::default()
+                        },
+                    )?;
+                    outln!("Design document {} updated successfully", id);
                     Ok(())
                 }
             }
         }
         Commands::Check { operation, path } => {
             match operation {
-                CheckOperation::Run { path } => {
-                    println!(
+                CheckOperation::Run {
+                    path,
+                    check_outdated,
+                    parallel,
+                    output,
+                    stale_days,
+                    timeout,
+                } => {
+                    // Keep stdout for the JSON report
+                    let json = matches!(output, RunOutputFormat::Json);
+                    fargin::output::set_human_to_stderr(json);
+                    outln!(
                         "🔍 Running comprehensive project checks at: {}",
                         path.display()
                     );
-                    let project_checker = ProjectChecker::new(path.as_path());
-                    match project_checker.run_project_checks() {
+                    let checker = fargin::check::ProjectChecker::new(path.as_path())
+                        .with_stale_after_days(stale_days)
+                        .with_timeout(timeout.map(std::time::Duration::from_secs))
+                        .with_outdated_dependencies(check_outdated);
+                    let checks = if parallel {
+                        checker.run_project_checks_parallel()
+                    } else {
+                        checker.run_project_checks()
+                    };
+                    let result = checks.and_then(|_| {
+                        // The JSON report lists them instead
+                        if check_outdated && !json {
+                            report_outdated_dependencies(&checker)?;
+                        }
+                        Ok(())
+                    });
+                    // The report is printed whether or not the checks passed
+                    if json {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&checker.run_all_checks()?)?
+                        );
+                    }
+                    match result {
                         Ok(_) => {
-                            println!("✅ Project checks completed successfully!");
+                            outln!("✅ Project checks completed successfully!");
                             Ok(())
                         }
                         Err(e) => {
-                            eprintln!("❌ Project checks failed: {}", e);
+                            eoutln!("❌ Project checks failed: {}", e);
                             Err(e)
                         }
                     }
                 }
-                CheckOperation::Fmt { path } => {
-                    println!("🧹 Running code formatting checks at: {}", path.display());
-                    let mut fmt_cmd = std::process::Command::new("cargo");
-                    fmt_cmd.arg("fmt").current_dir(&path);
+                CheckOperation::Fmt {
+                    path, diff: true, ..
+                } => {
+                    outln!("🧹 Checking formatting diff at: {}", path.display());
+                    let report = fargin::check::ProjectChecker::new(path.as_path())
+                        .check_formatting_diff()?;
 
-                    match fmt_cmd.output() {
-                        Ok(output) => {
-                            if output.status.success() {
-                                println!("✅ Code formatting check passed");
-                                Ok(())
-                            } else {
-                                eprintln!("❌ Code formatting check failed");
-                                Err(anyhow::anyhow!("Formatting check failed"))
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("❌ Error running formatting check: {}", e);
-                            Err(anyhow::anyhow!(e))
-                        }
+                    if report.is_clean() {
+                        outln!("✅ Code formatting check passed");
+                        Ok(())
+                    } else {
+                        outln!("{}", report.diff);
+                        outln!(
+                            "❌ Formatting changes needed in {} file(s):",
+                            report.files.len()
+                        );
+                        out!("{}", report.generate_summary());
+                        Err(
+                            fargin::check::CheckFailed("Formatting check failed".to_string())
+                                .into(),
+                        )
                     }
                 }
-                CheckOperation::Lint { path } => {
-                    println!("🔬 Running linting checks at: {}", path.display());
-                    let mut clippy_cmd = std::process::Command::new("cargo");
-                    clippy_cmd
-                        .args(["clippy", "--", "-D", "warnings"])
-                        .current_dir(&path);
-
-                    match clippy_cmd.output() {
+                CheckOperation::Fmt { path, timeout, .. } => {
+                    outln!("🧹 Running code formatting checks at: {}", path.display());
+                    match fargin::check::ProjectChecker::new(path.as_path())
+                        .with_timeout(timeout.map(std::time::Duration::from_secs))
+                        .run_fmt()
+                    {
                         Ok(output) => {
                             if output.status.success() {
-                                println!("✅ Linting checks passed");
+                                outln!("✅ Code formatting check passed");
                                 Ok(())
                             } else {
-                                eprintln!("❌ Linting checks failed");
-                                Err(anyhow::anyhow!("Linting check failed"))
+                                eoutln!("❌ Code formatting check failed");
+                                Err(fargin::check::CheckFailed(
+                                    "Formatting check failed".to_string(),
+                                )
+                                .into())
                             }
                         }
                         Err(e) => {
-                            eprintln!("❌ Error running linting checks: {}", e);
-                            Err(anyhow::anyhow!(e))
+                            eoutln!("❌ Error running formatting check: {}", e);
+                            Err(e)
                         }
                     }
                 }
-                CheckOperation::Test { path } => {
-                    println!("🧪 Running unit tests at: {}", path.display());
-                    let mut test_cmd = std::process::Command::new("cargo");
-                    test_cmd.arg("test").current_dir(&path);
+                CheckOperation::Lint { path, timeout } => {
+                    outln!("🕵️ Running linting checks...");
+                    let report = fargin::check::ProjectChecker::new(path.as_path())
+                        .with_timeout(timeout.map(std::time::Duration::from_secs))
+                        .check_clippy()?;
 
-                    match test_cmd.output() {
+                    if report.total_warnings() > 0 {
+                        out!("{}", report.generate_summary());
+                    }
+                    if report.passed() {
+                        outln!("✅ Linting checks passed");
+                        Ok(())
+                    } else {
+                        eoutln!("❌ Linting checks failed");
+                        Err(fargin::check::CheckFailed("Linting check failed".to_string()).into())
+                    }
+                }
+                CheckOperation::Test { path, timeout } => {
+                    outln!("🧪 Running unit tests at: {}", path.display());
+                    match fargin::check::ProjectChecker::new(path.as_path())
+                        .with_timeout(timeout.map(std::time::Duration::from_secs))
+                        .run_tests()
+                    {
                         Ok(output) => {
                             if output.status.success() {
-                                println!("✅ All unit tests passed");
+                                outln!("✅ All unit tests passed");
                                 Ok(())
                             } else {
-                                eprintln!("❌ Some unit tests failed");
-                                Err(anyhow::anyhow!("Unit tests failed"))
+                                eoutln!("❌ Some unit tests failed");
+                                Err(fargin::check::CheckFailed("Unit tests failed".to_string())
+                                    .into())
                             }
                         }
                         Err(e) => {
-                            eprintln!("❌ Error running unit tests: {}", e);
-                            Err(anyhow::anyhow!(e))
+                            eoutln!("❌ Error running unit tests: {}", e);
+                            Err(e)
                         }
                     }
                 }
                 CheckOperation::Git => {
-                    println!("🌿 Checking Git repository status...");
-                    let git_report = ProjectChecker::new(path.as_path()).check_git_status()?;
-                    println!("🌿 Git Repository Health Report:");
-                    println!("Is Git Repository: {}", git_report.is_git_repo);
-                    println!(
+                    outln!("🌿 Checking Git repository status...");
+                    let git_report =
+                        fargin::check::ProjectChecker::new(path.as_path()).check_git_status()?;
+                    outln!("🌿 Git Repository Health Report:");
+                    outln!("Is Git Repository: {}", git_report.is_git_repo);
+                    outln!(
                         "Current Branch: {}",
                         git_report
                             .branch_name
                             .unwrap_or_else(|| "Unknown".to_string())
                     );
-                    println!("Uncommitted Changes: {}", git_report.uncommitted_changes);
-                    println!("Unpushed Commits: {}", git_report.unpushed_commits);
+                    outln!("Uncommitted Changes: {}", git_report.uncommitted_changes);
+                    outln!("Unpushed Commits: {}", git_report.unpushed_commits);
                     Ok(())
                 }
                 CheckOperation::Loop {
                     path,
                     interval,
                     iterations,
+                    until,
+                    watch,
+                    debounce,
+                    timeout,
                 } => {
-                    use std::thread;
                     use std::time::Duration;
 
-                    println!(
+                    outln!(
                         "🔁 Starting continuous project checks at: {}",
                         path.display()
                     );
-                    println!("   Interval: {} seconds", interval);
-                    println!("   Max Iterations: {}", iterations);
-
-                    let mut iteration_count = 0;
-                    loop {
-                        iteration_count += 1;
-                        println!("\n🕒 Check Iteration {}", iteration_count);
-
-                        let project_checker = ProjectChecker::new(path.as_path());
-                        match project_checker.run_project_checks() {
-                            Ok(_) => {
-                                println!("✅ Project checks completed successfully");
-                            }
-                            Err(e) => {
-                                eprintln!("❌ Project checks failed: {}", e);
-                            }
-                        }
-
-                        // Check iteration limit
-                        if iterations > 0 && iteration_count >= iterations {
-                            println!("🏁 Reached maximum iterations. Stopping.");
-                            break;
-                        }
+                    if watch {
+                        outln!("   Watching for changes (debounce {} ms)", debounce);
+                    } else {
+                        outln!("   Interval: {} seconds", interval);
+                    }
+                    outln!("   Max Iterations: {}", iterations);
+                    if let Some(until) = until {
+                        outln!("   Until: {:?}", until);
+                    }
 
-                        // Wait before next iteration
-                        thread::sleep(Duration::from_secs(interval));
+                    let project_checker = fargin::check::ProjectChecker::new(path.as_path())
+                        .with_timeout(timeout.map(Duration::from_secs));
+                    if watch {
+                        fargin::check::watch_check_loop(
+                            &path,
+                            Duration::from_millis(debounce),
+                            iterations,
+                            until,
+                            || project_checker.run_project_checks(),
+                        )?;
+                    } else {
+                        fargin::check::run_check_loop(
+                            Duration::from_secs(interval),
+                            iterations,
+                            until,
+                            || project_checker.run_project_checks(),
+                        );
                     }
 
                     Ok(())
@@ -635,56 +1015,148 @@ fn main() -> Result<()> {
                 CheckOperation::Progress {
                     verbosity,
                     output,
+                    stale_days,
                     path,
                 } => {
-                    let project_checker = fargin::check::ProjectChecker::new(path.as_path());
+                    let project_checker = fargin::check::ProjectChecker::new(path.as_path())
+                        .with_stale_after_days(stale_days);
                     let progress_summary = project_checker.generate_progress_summary(&verbosity)?;
 
                     // Apply output formatting
                     let formatted_summary = match output {
-                        fargin::cli::HowtoOutputFormat::Terminal => progress_summary,
-                        fargin::cli::HowtoOutputFormat::Markdown => {
-                            format!("```markdown\n{}\n```", progress_summary)
+                        ProgressOutputFormat::Terminal => progress_summary,
+                        ProgressOutputFormat::Markdown => {
+                            fargin::howto::to_markdown(&progress_summary)
+                        }
+                        ProgressOutputFormat::Html => {
+                            format!("<pre>{}</pre>", html_escape(&progress_summary))
                         }
-                        fargin::cli::HowtoOutputFormat::Html => {
-                            format!("<pre>{}</pre>", progress_summary)
+                        ProgressOutputFormat::Prometheus => {
+                            project_checker.run_all_checks()?.to_prometheus()
+                        }
+                        ProgressOutputFormat::Json => {
+                            serde_json::to_string_pretty(&project_checker.run_all_checks()?)?
                         }
                     };
 
-                    println!("{}", formatted_summary);
+                    // Only the terminal summary is human text; the rest is printed untouched
+                    if matches!(output, ProgressOutputFormat::Terminal) {
+                        outln!("{}", formatted_summary);
+                    } else {
+                        println!("{}", formatted_summary);
+                    }
                     Ok(())
                 }
             }
         }
+        Commands::Undo { path, list } => {
+            if list {
+                let entries = fargin::undo::UndoJournal::new(&path).entries()?;
+                if entries.is_empty() {
+                    outln!("Nothing to undo.");
+                } else {
+                    outln!("Undo stack (most recent first):");
+                    for entry in entries.iter().rev() {
+                        outln!("  {} - {}", entry.timestamp.to_rfc3339(), entry.describe());
+                    }
+                }
+                return Ok(());
+            }
+
+            match fargin::undo::undo_last(&path)? {
+                Some(entry) => outln!("Undid: {}", entry.describe()),
+                None => outln!("Nothing to undo."),
+            }
+            Ok(())
+        }
+        Commands::Config { operation, path } => {
+            let mut config = ProjectConfig::load(&path)?;
+            match operation {
+                ConfigOperation::Get { key } => outln!("{}", config.get_field(&key)?),
+                ConfigOperation::Set { key, value } => {
+                    config.set_field(&key, &value)?;
+                    config.save(&path)?;
+                }
+                ConfigOperation::Show => print!("{}", toml::to_string_pretty(&config)?),
+            }
+            Ok(())
+        }
+        Commands::Status { output, path } => {
+            let status = fargin::status::ProjectStatus::collect(&path);
+            match output {
+                HowtoOutputFormat::Terminal => out!("{}", status.summary()),
+                HowtoOutputFormat::Markdown => print!("{}", status.to_markdown()),
+                HowtoOutputFormat::Html => {
+                    print!("{}", fargin::howto::markdown_to_html(&status.to_markdown()))
+                }
+                HowtoOutputFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&status)?)
+                }
+            }
+            Ok(())
+        }
+        Commands::Completions { shell } => {
+            fargin::cli::write_completions(shell, &mut std::io::stdout());
+            Ok(())
+        }
         Commands::Howto {
             topic,
             verbosity,
             output,
             save_path,
             list_topics,
+            path,
         } => {
+            let terminal = matches!(output, HowtoOutputFormat::Terminal);
+            let generator = fargin::howto::HowtoGenerator::new(topic, verbosity, output, save_path)
+                .with_project_path(&path);
+
             if list_topics {
-                println!("Available Howto Topics:");
-                for topic in fargin::howto::HowtoGenerator::list_topics() {
-                    println!("  - {}", topic);
+                outln!("Available Howto Topics:");
+                for topic in generator.available_topics()? {
+                    outln!("  - {}", topic);
                 }
                 return Ok(());
             }
 
-            let generator = fargin::howto::HowtoGenerator::new(topic, verbosity, output, save_path);
-
             let doc = generator.generate()?;
-            println!("{}", doc);
+            if terminal {
+                outln!("{}", doc);
+            } else {
+                println!("{}", doc);
+            }
 
             Ok(())
         }
-        Commands::Reset { scope, force } => {
-            // Placeholder for project reset
-            println!(
-                "Resetting project with scope: {:?}, force: {}",
-                scope, force
-            );
+        Commands::Reset {
+            scope,
+            force,
+            no_backup,
+            backup_dir,
+            path,
+        } => {
+            outln!("Resetting project with scope: {:?}", scope);
+            let options = fargin::reset::ResetOptions {
+                force,
+                no_backup,
+                backup_dir,
+            };
+            fargin::reset::reset_project(&path, scope, &options)?;
             Ok(())
         }
     }
 }
+
+/// Print dependencies that have newer releases available
+fn report_outdated_dependencies(checker: &fargin::check::ProjectChecker) -> Result<()> {
+    outln!("📦 Checking for outdated dependencies...");
+    let outdated = checker.check_outdated()?;
+    if outdated.is_empty() {
+        outln!("✅ No outdated dependencies found");
+    } else {
+        for dependency in outdated {
+            outln!("   - {}", dependency);
+        }
+    }
+    Ok(())
+}
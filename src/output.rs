@@ -0,0 +1,161 @@
+//! Terminal output helpers
+//!
+//! Human-readable messages go through [`outln!`](crate::outln) and
+//! [`out!`](crate::out), and failures through [`eoutln!`](crate::eoutln).
+//! All three drop emoji and ANSI escapes in plain mode. Machine-readable
+//! output (JSON, markdown, HTML) is printed directly and is never altered.
+
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable that disables decorated output when set to a non-empty value
+///
+/// See <https://no-color.org>.
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+static HUMAN_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Select plain output if `--no-color` was passed or `NO_COLOR` is set
+pub fn init(no_color: bool) {
+    let from_env = std::env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty());
+    set_plain(no_color || from_env);
+}
+
+/// Turn plain output on or off
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+}
+
+/// Whether decorations are currently stripped
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+/// Send human-readable messages and streamed tool output to stderr
+///
+/// Used when stdout carries a machine-readable report.
+pub fn set_human_to_stderr(to_stderr: bool) {
+    HUMAN_TO_STDERR.store(to_stderr, Ordering::Relaxed);
+}
+
+/// Whether human-readable messages currently go to stderr
+pub fn human_to_stderr() -> bool {
+    HUMAN_TO_STDERR.load(Ordering::Relaxed)
+}
+
+/// Where streamed tool output that would normally go to stdout should be written
+pub fn human_stdout() -> Box<dyn std::io::Write + Send> {
+    if human_to_stderr() {
+        Box::new(std::io::stderr())
+    } else {
+        Box::new(std::io::stdout())
+    }
+}
+
+/// `text` as it should appear on the terminal in the current mode
+pub fn decorate(text: &str) -> Cow<'_, str> {
+    if is_plain() {
+        Cow::Owned(strip_decorations(text))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Whether `c` is an emoji or pictograph rather than text
+pub fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF   // emoji, pictographs, flags
+            | 0x2300..=0x23FF // technical symbols such as ⏱ and ⏳
+            | 0x2600..=0x27BF // miscellaneous symbols and dingbats such as ✅ and ❌
+            | 0x2B00..=0x2BFF // stars and other arrows-and-symbols
+            | 0xFE0F          // emoji presentation selector
+            | 0x200D // zero-width joiner
+    )
+}
+
+/// Remove emoji and ANSI escape sequences, along with the space after an emoji
+pub fn strip_decorations(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI sequences such as colors end with a letter
+            if chars.next_if_eq(&'[').is_some() {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else if is_emoji(c) {
+            while chars.next_if(|&next| is_emoji(next)).is_some() {}
+            chars.next_if_eq(&' ');
+        } else {
+            plain.push(c);
+        }
+    }
+
+    plain
+}
+
+/// Like `println!`, but drops emoji and ANSI escapes in plain mode and
+/// moves to stderr when stdout carries a report
+#[macro_export]
+macro_rules! outln {
+    () => {
+        $crate::outln!("")
+    };
+    ($($arg:tt)*) => {
+        if $crate::output::human_to_stderr() {
+            eprintln!("{}", $crate::output::decorate(&format!($($arg)*)))
+        } else {
+            println!("{}", $crate::output::decorate(&format!($($arg)*)))
+        }
+    };
+}
+
+/// Like `eprintln!`, but drops emoji and ANSI escapes in plain mode
+///
+/// Used for failure messages, which always belong on stderr.
+#[macro_export]
+macro_rules! eoutln {
+    () => {
+        eprintln!()
+    };
+    ($($arg:tt)*) => {
+        eprintln!("{}", $crate::output::decorate(&format!($($arg)*)))
+    };
+}
+
+/// Like `print!`, with the same plain-mode and stderr handling as `outln!`
+#[macro_export]
+macro_rules! out {
+    ($($arg:tt)*) => {
+        if $crate::output::human_to_stderr() {
+            eprint!("{}", $crate::output::decorate(&format!($($arg)*)))
+        } else {
+            print!("{}", $crate::output::decorate(&format!($($arg)*)))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_decorations() {
+        assert_eq!(
+            strip_decorations("📊 Feature Statistics"),
+            "Feature Statistics"
+        );
+        assert_eq!(strip_decorations("✅ Healthy ❌ Not"), "Healthy Not");
+        assert_eq!(strip_decorations("\x1b[1;32mok\x1b[0m done"), "ok done");
+        assert_eq!(strip_decorations("⚠️ careful"), "careful");
+        assert_eq!(strip_decorations("a → b"), "a → b");
+    }
+}
@@ -0,0 +1,297 @@
+use crate::config::fargin_dir;
+use crate::undo::JOURNAL_PATH;
+use crate::{out, outln};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use clap::ValueEnum;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// What a reset removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ResetScope {
+    /// Generated state: prompts, history, and templates; config and the undo journal are kept
+    Soft,
+    /// The whole `.fargin` directory
+    Hard,
+    /// All feature files
+    Features,
+    /// Generated documentation
+    Docs,
+}
+
+impl ResetScope {
+    /// Subdirectories of `.fargin` removed by this scope; empty means all of it
+    fn dirs(self) -> &'static [&'static str] {
+        match self {
+            ResetScope::Soft => &["prompts", "history", "templates"],
+            ResetScope::Hard => &[],
+            ResetScope::Features => &["features"],
+            ResetScope::Docs => &["docs"],
+        }
+    }
+
+    /// Files inside the removed directories that survive this scope
+    fn kept(self) -> &'static [&'static str] {
+        match self {
+            ResetScope::Soft => &[JOURNAL_PATH],
+            _ => &[],
+        }
+    }
+}
+
+/// Options controlling how a reset is carried out
+#[derive(Debug, Clone, Default)]
+pub struct ResetOptions {
+    /// Skip the confirmation prompt
+    pub force: bool,
+
+    /// Skip the backup normally taken before a hard reset
+    pub no_backup: bool,
+
+    /// Where to put the backup (default: the project directory)
+    pub backup_dir: Option<PathBuf>,
+}
+
+/// Reset fargin state for a project, limited to the given scope
+///
+/// Unless `force` is set, the user is asked to confirm first. A hard reset
+/// copies `.fargin` to `.fargin.backup-<timestamp>` before removing it;
+/// the backup location is returned.
+pub fn reset_project(
+    project_path: &Path,
+    scope: ResetScope,
+    options: &ResetOptions,
+) -> Result<Option<PathBuf>> {
+    let fargin_dir = fargin_dir(project_path);
+    let targets: Vec<PathBuf> = match scope {
+        ResetScope::Hard => vec![fargin_dir.clone()],
+        _ => scope
+            .dirs()
+            .iter()
+            .map(|dir| fargin_dir.join(dir))
+            .collect(),
+    };
+
+    let existing: Vec<&PathBuf> = targets.iter().filter(|path| path.exists()).collect();
+    if existing.is_empty() {
+        outln!("Nothing to reset.");
+        return Ok(None);
+    }
+
+    if !options.force && !confirm(&existing)? {
+        outln!("Reset cancelled.");
+        return Ok(None);
+    }
+
+    let backup = if scope == ResetScope::Hard && !options.no_backup {
+        let backup_root = options.backup_dir.as_deref().unwrap_or(project_path);
+        let dir_name = fargin_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| ".fargin".to_string());
+        let backup_path = backup_root.join(format!(
+            "{}.backup-{}",
+            dir_name,
+            Utc::now().format("%Y%m%d_%H%M%S")
+        ));
+        copy_dir(&fargin_dir, &backup_path)
+            .with_context(|| format!("Failed to back up to {}", backup_path.display()))?;
+        outln!(
+            "💾 Backed up {} to {}",
+            fargin_dir.display(),
+            backup_path.display()
+        );
+        Some(backup_path)
+    } else {
+        None
+    };
+
+    let kept: Vec<PathBuf> = scope
+        .kept()
+        .iter()
+        .map(|file| fargin_dir.join(file))
+        .filter(|file| file.exists())
+        .collect();
+    for path in existing {
+        if kept.iter().any(|file| file.starts_with(path)) {
+            remove_dir_contents_except(path, &kept)?;
+            outln!("🗑️  Cleared {} (undo journal kept)", path.display());
+        } else {
+            fs::remove_dir_all(path)?;
+            outln!("🗑️  Removed {}", path.display());
+        }
+    }
+
+    Ok(backup)
+}
+
+/// Remove everything in `dir` apart from the `kept` files
+fn remove_dir_contents_except(dir: &Path, kept: &[PathBuf]) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if kept.iter().any(|file| file == &path) {
+            continue;
+        }
+        if kept.iter().any(|file| file.starts_with(&path)) {
+            remove_dir_contents_except(&path, kept)?;
+        } else if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else {
+            fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Recursively copy a directory
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Ask the user to confirm removing the given paths
+fn confirm(paths: &[&PathBuf]) -> Result<bool> {
+    outln!("The following will be permanently removed:");
+    for path in paths {
+        outln!("  - {}", path.display());
+    }
+    out!("Continue? [y/N] ");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::{FeatureManager, NewFeature};
+    use crate::undo::undo_last;
+    use tempfile::tempdir;
+
+    const TREE: [&str; 5] = ["prompts", "history", "templates", "features", "docs"];
+
+    /// Reset a full `.fargin` tree and return the entries left in it
+    fn remaining_after(scope: ResetScope) -> Result<Vec<String>> {
+        let temp_dir = tempdir()?;
+        let fargin_dir = temp_dir.path().join(".fargin");
+        for dir in TREE {
+            fs::create_dir_all(fargin_dir.join(dir))?;
+        }
+        fs::write(fargin_dir.join("config.toml"), "name = \"demo\"\n")?;
+
+        let options = ResetOptions {
+            force: true,
+            no_backup: true,
+            ..Default::default()
+        };
+        reset_project(temp_dir.path(), scope, &options)?;
+
+        if !fargin_dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut remaining: Vec<String> = fs::read_dir(&fargin_dir)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<Result<_>>()?;
+        remaining.sort();
+        Ok(remaining)
+    }
+
+    #[test]
+    fn test_soft_reset_keeps_config_and_content() -> Result<()> {
+        assert_eq!(
+            remaining_after(ResetScope::Soft)?,
+            vec!["config.toml", "docs", "features"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_soft_reset_keeps_undo_journal() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Reports".to_string(),
+            ..Default::default()
+        })?;
+        manager.delete_feature(&feature_id)?;
+        let history = fargin_dir(temp_dir.path()).join("history");
+        fs::create_dir_all(history.join("backups"))?;
+        fs::write(history.join("notes.md"), "old")?;
+
+        let options = ResetOptions {
+            force: true,
+            ..Default::default()
+        };
+        reset_project(temp_dir.path(), ResetScope::Soft, &options)?;
+
+        assert!(!history.join("notes.md").exists());
+        assert!(!history.join("backups").exists());
+        undo_last(temp_dir.path())?.expect("journal should survive a soft reset");
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert!(manager.get_feature(&feature_id).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_features_reset() -> Result<()> {
+        assert_eq!(
+            remaining_after(ResetScope::Features)?,
+            vec!["config.toml", "docs", "history", "prompts", "templates"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_docs_reset() -> Result<()> {
+        assert_eq!(
+            remaining_after(ResetScope::Docs)?,
+            vec!["config.toml", "features", "history", "prompts", "templates"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hard_reset() -> Result<()> {
+        assert!(remaining_after(ResetScope::Hard)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hard_reset_backs_up_first() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let backups = tempdir()?;
+        let fargin_dir = temp_dir.path().join(".fargin");
+        fs::create_dir_all(fargin_dir.join("features"))?;
+        fs::write(fargin_dir.join("config.toml"), "name = \"demo\"\n")?;
+
+        let options = ResetOptions {
+            force: true,
+            backup_dir: Some(backups.path().to_path_buf()),
+            ..Default::default()
+        };
+        let backup = reset_project(temp_dir.path(), ResetScope::Hard, &options)?
+            .expect("hard reset should back up");
+
+        assert!(!fargin_dir.exists());
+        assert!(backup.starts_with(backups.path()));
+        assert_eq!(
+            fs::read_to_string(backup.join("config.toml"))?,
+            "name = \"demo\"\n"
+        );
+        assert!(backup.join("features").is_dir());
+        Ok(())
+    }
+}
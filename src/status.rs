@@ -0,0 +1,207 @@
+use serde::Serialize;
+use std::path::Path;
+
+use crate::check::{GitHealthReport, ProjectChecker};
+use crate::config::ProjectConfig;
+use crate::features::{FeatureManager, FeatureStats};
+
+/// One-screen summary of a project, as shown by `fargin status`
+#[derive(Debug, Default, Serialize)]
+pub struct ProjectStatus {
+    /// Project name from `.fargin/config.toml`, if the project is initialized
+    pub project_name: Option<String>,
+
+    /// Feature rollup, if the features could be loaded
+    pub features: Option<FeatureStats>,
+
+    /// Completed progress markers
+    pub completed_markers: usize,
+
+    /// All progress markers
+    pub total_markers: usize,
+
+    /// Repository state, if the git status could be read
+    pub git: Option<GitHealthReport>,
+
+    /// Subsystems that could not be read, with the reason
+    pub warnings: Vec<String>,
+}
+
+impl ProjectStatus {
+    /// Gather the status of the project at `path`
+    ///
+    /// A missing or broken subsystem is recorded in `warnings` instead of
+    /// failing the whole report.
+    pub fn collect(path: &Path) -> Self {
+        let mut status = ProjectStatus::default();
+
+        match ProjectConfig::load(path) {
+            Ok(config) => {
+                status.project_name = Some(config.name);
+                status.total_markers = config.progress_markers.len();
+                status.completed_markers = config
+                    .progress_markers
+                    .iter()
+                    .filter(|marker| marker.completed)
+                    .count();
+            }
+            Err(e) => status.warnings.push(format!("Config: {:#}", e)),
+        }
+
+        match FeatureManager::open(path) {
+            Ok(manager) => status.features = Some(manager.statistics()),
+            Err(e) => status.warnings.push(format!("Features: {:#}", e)),
+        }
+
+        match ProjectChecker::new(path).check_git_status() {
+            Ok(git) => status.git = Some(git),
+            Err(e) => status.warnings.push(format!("Git: {:#}", e)),
+        }
+
+        status
+    }
+
+    /// Share of progress markers completed, as a percentage
+    pub fn percent_complete(&self) -> f64 {
+        if self.total_markers == 0 {
+            0.0
+        } else {
+            (self.completed_markers as f64 / self.total_markers as f64 * 100.0).round()
+        }
+    }
+
+    /// Human-readable terminal report
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "📁 Project: {}\n",
+            self.project_name.as_deref().unwrap_or("(not initialized)")
+        );
+
+        match &self.features {
+            Some(features) => {
+                summary.push_str(&format!("🧩 Features: {} total\n", features.total));
+                for (status, count) in &features.by_status {
+                    summary.push_str(&format!("   - {}: {}\n", status, count));
+                }
+            }
+            None => summary.push_str("🧩 Features: unavailable\n"),
+        }
+
+        summary.push_str(&format!(
+            "📈 Progress: {}/{} markers ({:.0}%)\n",
+            self.completed_markers,
+            self.total_markers,
+            self.percent_complete()
+        ));
+        summary.push_str(&format!("🌿 Git: {}\n", self.git_summary()));
+
+        for warning in &self.warnings {
+            summary.push_str(&format!("⚠️  {}\n", warning));
+        }
+
+        summary
+    }
+
+    /// Markdown report
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "# Project Status: {}\n\n",
+            self.project_name.as_deref().unwrap_or("(not initialized)")
+        );
+
+        markdown.push_str("## Features\n\n");
+        match &self.features {
+            Some(features) => {
+                markdown.push_str(&format!("- **Total**: {}\n", features.total));
+                for (status, count) in &features.by_status {
+                    markdown.push_str(&format!("- **{}**: {}\n", status, count));
+                }
+            }
+            None => markdown.push_str("- Unavailable\n"),
+        }
+
+        markdown.push_str(&format!(
+            "\n## Progress\n\n- {}/{} markers ({:.0}%)\n\n## Git\n\n- {}\n",
+            self.completed_markers,
+            self.total_markers,
+            self.percent_complete(),
+            self.git_summary()
+        ));
+
+        if !self.warnings.is_empty() {
+            markdown.push_str("\n## Warnings\n\n");
+            for warning in &self.warnings {
+                markdown.push_str(&format!("- {}\n", warning));
+            }
+        }
+
+        markdown
+    }
+
+    fn git_summary(&self) -> String {
+        match &self.git {
+            Some(git) if git.is_git_repo => format!(
+                "{} ({} uncommitted, {} unpushed)",
+                git.branch_name.as_deref().unwrap_or("detached"),
+                git.uncommitted_changes,
+                git.unpushed_commits
+            ),
+            Some(_) => "not a git repository".to_string(),
+            None => "unavailable".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ProgressMarker;
+    use crate::features::{FeatureStatus, NewFeature};
+    use anyhow::Result;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_status_of_initialized_project() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut config = ProjectConfig::new("Orbit".to_string(), "Test".to_string());
+        let mut marker = ProgressMarker::new("MVP".to_string(), "First release".to_string());
+        marker.complete();
+        config.progress_markers = vec![
+            marker,
+            ProgressMarker::new("Beta".to_string(), "Second".to_string()),
+        ];
+        config.save(temp_dir.path())?;
+
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+        for name in ["Login", "Search"] {
+            manager.add_feature(NewFeature {
+                name: name.to_string(),
+                ..Default::default()
+            })?;
+        }
+
+        let status = ProjectStatus::collect(temp_dir.path());
+        let summary = status.summary();
+
+        assert!(summary.contains("Project: Orbit"));
+        assert!(summary.contains("Features: 2 total"));
+        assert!(summary.contains(&format!("{}: 2", FeatureStatus::Proposed)));
+        assert!(summary.contains("1/2 markers (50%)"));
+        assert!(summary.contains("Git: not a git repository"));
+        assert!(status.warnings.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_degrades_without_config() {
+        let temp_dir = tempdir().unwrap();
+
+        let status = ProjectStatus::collect(temp_dir.path());
+
+        assert!(status.project_name.is_none());
+        assert_eq!(status.features.as_ref().map(|f| f.total), Some(0));
+        assert_eq!(status.percent_complete(), 0.0);
+        assert!(status.warnings.iter().any(|w| w.starts_with("Config:")));
+    }
+}
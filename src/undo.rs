@@ -0,0 +1,428 @@
+use crate::config::fargin_dir;
+use crate::error::{FarginError, Result};
+use crate::features::{Feature, FeatureManager};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Location of the undo journal inside the fargin directory
+pub(crate) const JOURNAL_PATH: &str = "history/undo.jsonl";
+
+/// How many operations the journal remembers; older ones are dropped
+pub const MAX_ENTRIES: usize = 100;
+
+/// A destructive operation that can be reverted
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UndoOperation {
+    /// A feature was deleted; holds its full content
+    FeatureRemoved { feature: Feature },
+
+    /// A feature was modified; holds its state before the update
+    FeatureUpdated { before: Feature },
+
+    /// A feature was given a new ID; holds it as it was under the old one
+    FeatureMoved { from: Feature, to: String },
+
+    /// Several changes made by one command, reverted together
+    Batch {
+        summary: String,
+        operations: Vec<UndoOperation>,
+    },
+}
+
+/// Single entry in the undo journal
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UndoEntry {
+    /// When the operation happened
+    pub timestamp: DateTime<Utc>,
+
+    /// What needs to be restored to revert the operation
+    pub operation: UndoOperation,
+}
+
+impl UndoEntry {
+    /// Human-readable summary of the recorded operation
+    pub fn describe(&self) -> String {
+        match &self.operation {
+            UndoOperation::FeatureRemoved { feature } => {
+                format!("Removed feature {} ({})", feature.id, feature.name)
+            }
+            UndoOperation::FeatureUpdated { before } => {
+                format!("Updated feature {} ({})", before.id, before.name)
+            }
+            UndoOperation::FeatureMoved { from, to } => {
+                format!("Moved feature {} ({}) to {}", from.id, from.name, to)
+            }
+            UndoOperation::Batch { summary, .. } => summary.clone(),
+        }
+    }
+}
+
+/// Append-only journal of destructive operations stored in `.fargin/history/undo.jsonl`
+pub struct UndoJournal {
+    journal_path: PathBuf,
+}
+
+impl UndoJournal {
+    /// Open the undo journal for a project
+    pub fn new(project_path: &Path) -> Self {
+        Self {
+            journal_path: fargin_dir(project_path).join(JOURNAL_PATH),
+        }
+    }
+
+    /// Record an operation at the top of the undo stack
+    pub fn record(&self, operation: UndoOperation) -> Result<()> {
+        if let Some(parent) = self.journal_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let entry = UndoEntry {
+            timestamp: Utc::now(),
+            operation,
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.journal_path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+
+        let entries = self.entries()?;
+        if entries.len() > MAX_ENTRIES {
+            self.write_entries(&entries[entries.len() - MAX_ENTRIES..])?;
+        }
+
+        Ok(())
+    }
+
+    /// All recorded operations, oldest first
+    pub fn entries(&self) -> Result<Vec<UndoEntry>> {
+        if !self.journal_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        fs::read_to_string(&self.journal_path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| FarginError::Parse {
+                    path: self.journal_path.clone(),
+                    message: format!("corrupt undo journal entry: {}", e),
+                })
+            })
+            .collect()
+    }
+
+    /// Drop the most recent entry from the journal
+    fn remove_last(&self) -> Result<()> {
+        let mut entries = self.entries()?;
+        entries.pop();
+        self.write_entries(&entries)
+    }
+
+    /// Replace the journal with `entries`
+    fn write_entries(&self, entries: &[UndoEntry]) -> Result<()> {
+        let mut content = String::new();
+        for entry in entries {
+            content.push_str(&serde_json::to_string(entry)?);
+            content.push('\n');
+        }
+        fs::write(&self.journal_path, content)?;
+
+        Ok(())
+    }
+}
+
+/// Revert the most recent destructive operation, returning what was undone
+pub fn undo_last(project_path: &Path) -> Result<Option<UndoEntry>> {
+    let journal = UndoJournal::new(project_path);
+    let Some(entry) = journal.entries()?.pop() else {
+        return Ok(None);
+    };
+
+    let mut feature_manager = FeatureManager::new(project_path)?;
+    revert(project_path, &mut feature_manager, &entry.operation)?;
+
+    journal.remove_last()?;
+    Ok(Some(entry))
+}
+
+fn revert(
+    project_path: &Path,
+    feature_manager: &mut FeatureManager,
+    operation: &UndoOperation,
+) -> Result<()> {
+    match operation {
+        UndoOperation::FeatureRemoved { feature } => {
+            feature_manager.restore_feature(feature.clone())?;
+            // A feature that is live again should not also sit in the archive
+            let archived = fargin_dir(project_path)
+                .join("features/archive")
+                .join(format!("{}.md", feature.id));
+            if archived.exists() {
+                fs::remove_file(archived)?;
+            }
+        }
+        UndoOperation::FeatureUpdated { before } => {
+            feature_manager.restore_feature(before.clone())?
+        }
+        UndoOperation::FeatureMoved { from, to } => {
+            feature_manager.restore_feature(from.clone())?;
+            let moved = fargin_dir(project_path)
+                .join("features")
+                .join(format!("{}.md", to));
+            if moved.exists() {
+                fs::remove_file(moved)?;
+            }
+            let history = feature_manager.history_path(to);
+            if history.exists() {
+                fs::rename(history, feature_manager.history_path(&from.id))?;
+            }
+        }
+        UndoOperation::Batch { operations, .. } => {
+            // Check every feature can be written before touching any file
+            for feature in operations.iter().flat_map(restored_features) {
+                FeatureManager::feature_to_file(feature)?;
+            }
+            for operation in operations.iter().rev() {
+                revert(project_path, feature_manager, operation)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Features an operation writes back when it is reverted
+fn restored_features(operation: &UndoOperation) -> Vec<&Feature> {
+    match operation {
+        UndoOperation::FeatureRemoved { feature } => vec![feature],
+        UndoOperation::FeatureUpdated { before } => vec![before],
+        UndoOperation::FeatureMoved { from, .. } => vec![from],
+        UndoOperation::Batch { operations, .. } => {
+            operations.iter().flat_map(restored_features).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::{
+        FeatureStatus, FeatureUpdateRequest, MergePreference, NewFeature, RankPlacement,
+    };
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_undo_feature_update() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Billing".to_string(),
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &feature_id,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::Blocked),
+                ..Default::default()
+            },
+        )?;
+
+        let undone = undo_last(temp_dir.path())?.expect("update should be journaled");
+        assert_eq!(
+            undone.describe(),
+            format!("Updated feature {} (Billing)", feature_id)
+        );
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        let feature = manager
+            .get_feature(&feature_id)
+            .expect("Feature should exist");
+        assert_eq!(feature.status, FeatureStatus::Proposed);
+        assert!(UndoJournal::new(temp_dir.path()).entries()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_feature_remove() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Reports".to_string(),
+            ..Default::default()
+        })?;
+        manager.delete_feature(&feature_id)?;
+        assert_eq!(UndoJournal::new(temp_dir.path()).entries()?.len(), 1);
+
+        undo_last(temp_dir.path())?;
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert_eq!(
+            manager.get_feature(&feature_id).map(|f| f.name.as_str()),
+            Some("Reports")
+        );
+        assert!(undo_last(temp_dir.path())?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_merge_restores_every_feature() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let keep_id = manager.add_feature(NewFeature {
+            name: "Login".to_string(),
+            ..Default::default()
+        })?;
+        let drop_id = manager.add_feature(NewFeature {
+            name: "Sign In".to_string(),
+            tags: vec!["oauth".to_string()],
+            ..Default::default()
+        })?;
+        let other_id = manager.add_feature(NewFeature {
+            name: "Profile".to_string(),
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &other_id,
+            FeatureUpdateRequest {
+                related_features: Some(vec![drop_id.clone()]),
+                ..Default::default()
+            },
+        )?;
+        let journaled_before = UndoJournal::new(temp_dir.path()).entries()?.len();
+
+        let summary = manager.merge_features(&keep_id, &drop_id, MergePreference::Keep)?;
+        assert_eq!(
+            UndoJournal::new(temp_dir.path()).entries()?.len(),
+            journaled_before + 1
+        );
+
+        let undone = undo_last(temp_dir.path())?.expect("merge should be journaled");
+        assert_eq!(
+            undone.describe(),
+            format!("Merged feature {} into {}", drop_id, keep_id)
+        );
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert!(manager.get_feature(&keep_id).unwrap().tags.is_empty());
+        assert_eq!(
+            manager.get_feature(&drop_id).map(|f| f.name.as_str()),
+            Some("Sign In")
+        );
+        assert_eq!(
+            manager.get_feature(&other_id).unwrap().related_features,
+            vec![drop_id.clone()]
+        );
+        assert!(!summary.archived_path.exists());
+        assert_eq!(
+            UndoJournal::new(temp_dir.path()).entries()?.len(),
+            journaled_before
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo_reassign_id_moves_feature_back() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let auth = manager.add_feature(NewFeature {
+            name: "Auth".to_string(),
+            ..Default::default()
+        })?;
+        let profile = manager.add_feature(NewFeature {
+            name: "Profile".to_string(),
+            related_features: vec![auth.clone()],
+            ..Default::default()
+        })?;
+        manager.update_feature(
+            &auth,
+            FeatureUpdateRequest {
+                status: Some(FeatureStatus::InProgress),
+                ..Default::default()
+            },
+        )?;
+        manager.reassign_id(&auth, "auth")?;
+
+        let undone = undo_last(temp_dir.path())?.expect("ID change should be journaled");
+        assert_eq!(
+            undone.describe(),
+            format!("Changed feature ID {} to auth", auth)
+        );
+
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert_eq!(manager.len(), 2);
+        assert!(manager.get_feature("auth").is_none());
+        assert_eq!(manager.get_feature(&auth).unwrap().name, "Auth");
+        assert_eq!(
+            manager.get_feature(&profile).unwrap().related_features,
+            vec![auth.clone()]
+        );
+        assert_eq!(manager.history(&auth)?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_journals_one_entry() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let first = manager.add_feature(NewFeature {
+            name: "First".to_string(),
+            ..Default::default()
+        })?;
+        let second = manager.add_feature(NewFeature {
+            name: "Second".to_string(),
+            ..Default::default()
+        })?;
+
+        // The unranked anchor gets a rank too, but it is one command
+        manager.rank_feature(&second, &first, RankPlacement::Above)?;
+        assert_eq!(UndoJournal::new(temp_dir.path()).entries()?.len(), 1);
+
+        undo_last(temp_dir.path())?;
+        let manager = FeatureManager::new(temp_dir.path())?;
+        assert_eq!(manager.get_feature(&first).unwrap().rank, None);
+        assert_eq!(manager.get_feature(&second).unwrap().rank, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_journal_is_capped() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut manager = FeatureManager::new(temp_dir.path())?;
+
+        let feature_id = manager.add_feature(NewFeature {
+            name: "Search".to_string(),
+            ..Default::default()
+        })?;
+        for n in 0..MAX_ENTRIES + 5 {
+            manager.rename(&feature_id, &format!("Search {}", n))?;
+        }
+
+        let entries = UndoJournal::new(temp_dir.path()).entries()?;
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(
+            entries.last().unwrap().describe(),
+            format!(
+                "Updated feature {} (Search {})",
+                feature_id,
+                MAX_ENTRIES + 3
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::tempdir;
+
+fn fargin(project: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fargin"))
+        .args(args)
+        .current_dir(project)
+        .output()
+        .expect("failed to run fargin")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "fargin failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn feature_add_with_acceptance_criteria_is_shown() {
+    let project = tempdir().unwrap();
+
+    let added = stdout(&fargin(
+        project.path(),
+        &[
+            "feature",
+            "add",
+            "Login",
+            "--acceptance-criteria",
+            "Users can sign in,Bad passwords are rejected",
+        ],
+    ));
+    let id = added
+        .trim()
+        .strip_prefix("Feature added with ID: ")
+        .expect("add prints the new ID");
+
+    let shown = stdout(&fargin(project.path(), &["feature", "show", id]));
+    assert!(shown.contains("Acceptance Criteria:"));
+    assert!(shown.contains("  - Users can sign in"));
+    assert!(shown.contains("  - Bad passwords are rejected"));
+}
+
+#[test]
+fn json_output_is_parseable() {
+    let project = tempdir().unwrap();
+
+    let added = stdout(&fargin(
+        project.path(),
+        &["feature", "add", "Search", "--tags", "api"],
+    ));
+    let id = added
+        .trim()
+        .strip_prefix("Feature added with ID: ")
+        .expect("add prints the new ID")
+        .to_string();
+
+    let commands: [&[&str]; 4] = [
+        &["feature", "list", "--output", "json"],
+        &["feature", "stats", "--output", "json"],
+        &["feature", "suggest", &id, "--output", "json", "--explain"],
+        &["howto", "git-health", "--output", "json"],
+    ];
+
+    for args in commands {
+        let out = stdout(&fargin(project.path(), args));
+        let parsed: Result<serde_json::Value, _> = serde_json::from_str(&out);
+        assert!(parsed.is_ok(), "{:?} printed invalid JSON:\n{}", args, out);
+    }
+}
+
+#[test]
+fn fargin_dir_env_relocates_metadata() {
+    let project = tempdir().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fargin"))
+        .args(["feature", "add", "Relocated"])
+        .env("FARGIN_DIR", ".mymeta")
+        .current_dir(project.path())
+        .output()
+        .expect("failed to run fargin");
+    let added = stdout(&output);
+    let id = added
+        .trim()
+        .strip_prefix("Feature added with ID: ")
+        .expect("add prints the new ID");
+
+    assert!(project
+        .path()
+        .join(format!(".mymeta/features/{}.md", id))
+        .exists());
+    assert!(!project.path().join(".fargin").exists());
+}
+
+#[test]
+fn check_failures_exit_with_check_status() {
+    let project = tempdir().unwrap();
+    std::fs::write(
+        project.path().join("Cargo.toml"),
+        "[package]\nname = \"badfmt\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir(project.path().join("src")).unwrap();
+    std::fs::write(
+        project.path().join("src/main.rs"),
+        "fn main( ){println!(\"hi\") ;}\n",
+    )
+    .unwrap();
+
+    let failed = fargin(project.path(), &["check", "fmt", "--diff"]);
+    assert_eq!(failed.status.code(), Some(fargin::EXIT_CHECK_FAILED));
+
+    std::fs::write(
+        project.path().join("src/main.rs"),
+        "fn main() {\n    println!(\"hi\");\n}\n",
+    )
+    .unwrap();
+    let passed = fargin(project.path(), &["check", "fmt", "--diff"]);
+    assert_eq!(passed.status.code(), Some(0));
+
+    let usage = fargin(project.path(), &["feature", "show", "does-not-exist"]);
+    assert_eq!(usage.status.code(), Some(fargin::EXIT_USAGE));
+}
+
+#[cfg(unix)]
+#[test]
+fn custom_check_steps_run_after_builtins() {
+    let project = tempdir().unwrap();
+    std::fs::create_dir(project.path().join(".fargin")).unwrap();
+    std::fs::write(
+        project.path().join(".fargin/checks.toml"),
+        "[[checks]]\nname = \"greet\"\ncommand = \"echo\"\nargs = [\"hello from a custom step\"]\n",
+    )
+    .unwrap();
+
+    let out = stdout(&fargin(project.path(), &["check", "run"]));
+    assert!(out.contains("[greet] hello from a custom step"), "{}", out);
+
+    std::fs::write(
+        project.path().join(".fargin/checks.toml"),
+        "[[checks]]\nname = \"always-fails\"\ncommand = \"false\"\n",
+    )
+    .unwrap();
+
+    let failed = Command::new(env!("CARGO_BIN_EXE_fargin"))
+        .args(["check", "run"])
+        .env_remove("NO_COLOR")
+        .current_dir(project.path())
+        .output()
+        .expect("failed to run fargin");
+    assert_eq!(failed.status.code(), Some(fargin::EXIT_CHECK_FAILED));
+    let stderr = String::from_utf8_lossy(&failed.stderr);
+    assert!(stderr.contains("❌ Project checks failed"), "{}", stderr);
+
+    let plain = fargin(project.path(), &["--no-color", "check", "run"]);
+    let stderr = String::from_utf8_lossy(&plain.stderr);
+    assert!(stderr.contains("Project checks failed"), "{}", stderr);
+    assert!(!stderr.contains('❌'), "{}", stderr);
+}
+
+#[test]
+fn init_rust_dry_run_plans_template() {
+    let project = tempdir().unwrap();
+
+    let out = stdout(&fargin(
+        project.path(),
+        &[
+            "init",
+            "rust",
+            "demo",
+            "--template",
+            "gh:acme/starter",
+            "--dry-run",
+        ],
+    ));
+    assert!(
+        out.contains("cargo generate --name demo --git gh:acme/starter"),
+        "{}",
+        out
+    );
+    assert!(!project.path().join("demo").exists());
+    assert!(!project.path().join(".fargin").exists());
+
+    let json = stdout(&fargin(
+        project.path(),
+        &["init", "minimal", "demo", "--dry-run", "--output", "json"],
+    ));
+    let plan: serde_json::Value = serde_json::from_str(&json).expect("plan is JSON");
+    let files = plan["files"].as_array().expect("plan lists files");
+    assert!(files
+        .iter()
+        .any(|file| file.as_str().is_some_and(|f| f.ends_with("Cargo.toml"))));
+    assert!(!project.path().join("demo").exists());
+}
+
+#[test]
+fn no_color_strips_emoji_but_not_json() {
+    let project = tempdir().unwrap();
+    stdout(&fargin(project.path(), &["feature", "add", "Search 🔍"]));
+
+    let has_emoji = |out: &str| out.chars().any(fargin::output::is_emoji) || out.contains('\x1b');
+
+    let decorated = stdout(
+        &Command::new(env!("CARGO_BIN_EXE_fargin"))
+            .args(["feature", "stats"])
+            .env_remove("NO_COLOR")
+            .current_dir(project.path())
+            .output()
+            .expect("failed to run fargin"),
+    );
+    assert!(has_emoji(&decorated), "{}", decorated);
+
+    for args in [
+        &["--no-color", "feature", "stats"][..],
+        &["feature", "list", "--no-color"][..],
+    ] {
+        let out = stdout(&fargin(project.path(), args));
+        assert!(!has_emoji(&out), "{:?} printed decorations:\n{}", args, out);
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fargin"))
+        .args(["feature", "stats"])
+        .env("NO_COLOR", "1")
+        .current_dir(project.path())
+        .output()
+        .expect("failed to run fargin");
+    let out = stdout(&output);
+    assert!(!has_emoji(&out), "NO_COLOR printed decorations:\n{}", out);
+
+    let json = stdout(&fargin(
+        project.path(),
+        &["--no-color", "feature", "list", "--output", "json"],
+    ));
+    assert!(json.contains("Search 🔍"), "{}", json);
+
+    let markdown = stdout(&fargin(
+        project.path(),
+        &["--no-color", "check", "progress", "--output", "markdown"],
+    ));
+    assert!(
+        markdown.contains("🚀 Project Progress Summary"),
+        "{}",
+        markdown
+    );
+}
+
+#[test]
+fn check_git_reports_real_repository() {
+    let project = tempdir().unwrap();
+    let init = Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(project.path())
+        .status();
+    if !init.is_ok_and(|status| status.success()) {
+        return;
+    }
+
+    let out = stdout(&fargin(project.path(), &["check", "git"]));
+    assert!(out.contains("Is Git Repository: true"), "{}", out);
+}
+
+#[cfg(unix)]
+#[test]
+fn check_run_json_keeps_stdout_parseable_on_failure() {
+    let project = tempdir().unwrap();
+    std::fs::create_dir(project.path().join(".fargin")).unwrap();
+    std::fs::write(
+        project.path().join(".fargin/checks.toml"),
+        "[[checks]]\nname = \"greet\"\ncommand = \"echo\"\nargs = [\"hello\"]\n\n\
+         [[checks]]\nname = \"always-fails\"\ncommand = \"false\"\n",
+    )
+    .unwrap();
+
+    let failed = fargin(project.path(), &["check", "run", "--output", "json"]);
+    assert_eq!(failed.status.code(), Some(fargin::EXIT_CHECK_FAILED));
+    let out = String::from_utf8_lossy(&failed.stdout);
+    let parsed: Result<serde_json::Value, _> = serde_json::from_str(&out);
+    assert!(parsed.is_ok(), "stdout is not JSON:\n{}", out);
+    assert!(String::from_utf8_lossy(&failed.stderr).contains("[greet] hello"));
+}
@@ -0,0 +1,22 @@
+use fargin::config::ProjectConfig;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn saving_twice_overwrites_a_read_only_config() {
+    let project = tempdir().unwrap();
+    let config_path = project.path().join(".fargin/config.toml");
+
+    let mut config = ProjectConfig::new("demo".to_string(), "First".to_string());
+    config.save(project.path()).unwrap();
+
+    let mut perms = fs::metadata(&config_path).unwrap().permissions();
+    perms.set_readonly(true);
+    fs::set_permissions(&config_path, perms).unwrap();
+
+    config.description = "Second".to_string();
+    config.save(project.path()).unwrap();
+
+    let reloaded = ProjectConfig::load(project.path()).unwrap();
+    assert_eq!(reloaded.description, "Second");
+}